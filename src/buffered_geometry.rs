@@ -0,0 +1,87 @@
+use crate::{GResult, Geom, Geometry};
+
+/// `BufferedGeometry` wraps a [`Geometry`] and keeps the result of its last
+/// [`buffer`](BufferedGeometry::buffer) call, so buffering it again with the same parameters
+/// doesn't recompute anything.
+///
+/// The cache only holds a single entry: a call with a different distance or number of quadrant
+/// segments replaces it.
+///
+/// # Example
+///
+/// ```
+/// use geos::{BufferedGeometry, Geom, Geometry};
+///
+/// let geom = Geometry::new_from_wkt("POINT(1 3)")?;
+/// let mut buffered_geom = BufferedGeometry::new(&geom);
+///
+/// let area = buffered_geom.buffer(10., 8)?.area()?;
+/// // This one comes from the cache.
+/// assert_eq!(buffered_geom.buffer(10., 8)?.area()?, area);
+/// # Ok::<(), geos::Error>(())
+/// ```
+pub struct BufferedGeometry<'a, G: Geom> {
+    geometry: &'a G,
+    cache: Option<(f64, i32, Geometry)>,
+    pub(crate) computed: usize,
+}
+
+impl<'a, G: Geom> BufferedGeometry<'a, G> {
+    /// Creates a new `BufferedGeometry` with an empty cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{BufferedGeometry, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POINT(1 3)")?;
+    /// let buffered_geom = BufferedGeometry::new(&geom);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn new(geometry: &'a G) -> Self {
+        BufferedGeometry {
+            geometry,
+            cache: None,
+            computed: 0,
+        }
+    }
+
+    /// Returns the wrapped geometry.
+    pub fn geometry(&self) -> &'a G {
+        self.geometry
+    }
+
+    /// Same as [`Geom::buffer`], but returns the cached result when called with the same
+    /// `width` and `quadsegs` as the previous call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{BufferedGeometry, Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 10 0)")?;
+    /// let mut buffered_geom = BufferedGeometry::new(&geom);
+    ///
+    /// assert_eq!(
+    ///     buffered_geom.buffer(1., 8)?.to_wkt()?,
+    ///     geom.buffer(1., 8)?.to_wkt()?,
+    /// );
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn buffer(&mut self, width: f64, quadsegs: i32) -> GResult<&Geometry> {
+        let entry = match self.cache.take() {
+            Some(entry) if entry.0 == width && entry.1 == quadsegs => entry,
+            _ => {
+                let buffer = self.geometry.buffer(width, quadsegs)?;
+                self.computed += 1;
+                (width, quadsegs, buffer)
+            }
+        };
+        Ok(&self.cache.insert(entry).2)
+    }
+
+    /// Empties the cache.
+    pub fn clear(&mut self) {
+        self.cache = None;
+    }
+}
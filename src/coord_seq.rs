@@ -748,6 +748,10 @@ impl CoordSeq {
         })
     }
 
+    pub(crate) const fn coord_type(&self) -> CoordType {
+        self.coord_type
+    }
+
     /// Returns the number of dimensions of the `CoordSeq` object.
     ///
     /// # Example
@@ -513,3 +513,17 @@ impl TryFrom<c_int> for DimensionType {
         }
     }
 }
+
+/// Operations of a [`Geometry`](crate::Geometry) [overlay](crate::Geom::overlay).
+#[cfg(feature = "v3_9_0")]
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+pub enum OverlayOp {
+    /// See [`Geom::intersection`](crate::Geom::intersection).
+    Intersection,
+    /// See [`Geom::union`](crate::Geom::union).
+    Union,
+    /// See [`Geom::difference`](crate::Geom::difference).
+    Difference,
+    /// See [`Geom::sym_difference`](crate::Geom::sym_difference).
+    SymDifference,
+}
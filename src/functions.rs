@@ -2,7 +2,7 @@ use crate::context_handle::with_context;
 use crate::enums::*;
 use crate::error::{Error, GResult};
 use crate::geometry::Geometry;
-use crate::{AsRawMut, ContextHandle, Geom};
+use crate::{AsRawMut, ContextHandle, CoordSeq, Geom};
 use geos_sys::*;
 use std::convert::TryFrom;
 use std::ffi::CStr;
@@ -106,6 +106,152 @@ pub fn create_multi_geom(
     res
 }
 
+/// Calls `f` with the `CoordSeq` of every point, curve and polygon ring found in `geom`, going
+/// through collections recursively.
+pub fn for_each_coord_seq<G: Geom, F: FnMut(CoordSeq) -> GResult<()>>(
+    geom: &G,
+    f: &mut F,
+) -> GResult<()> {
+    match geom.geometry_type()? {
+        GeometryTypes::Point => f(geom.get_coord_seq()?),
+        t if t.is_curve() => f(geom.get_coord_seq()?),
+        GeometryTypes::Polygon => {
+            if geom.is_empty()? {
+                return Ok(());
+            }
+            for_each_coord_seq(&geom.get_exterior_ring()?, f)?;
+            for n in 0..geom.get_num_interior_rings()? {
+                for_each_coord_seq(&geom.get_interior_ring_n(n)?, f)?;
+            }
+            Ok(())
+        }
+        t if t.is_collection() => {
+            for n in 0..geom.get_num_geometries()? {
+                for_each_coord_seq(&geom.get_geometry_n(n)?, f)?;
+            }
+            Ok(())
+        }
+        t => Err(Error::ImpossibleOperation(format!(
+            "cannot iterate over the coordinates of a {t:?}"
+        ))),
+    }
+}
+
+/// Rebuilds `geom` with the same structure and SRID, replacing the `CoordSeq` of every point,
+/// line and polygon ring by the result of `f`. Sequences are visited in the same order as in
+/// [`for_each_coord_seq`].
+pub fn map_coord_seqs<G: Geom, F: FnMut(CoordSeq) -> GResult<CoordSeq>>(
+    geom: &G,
+    f: &mut F,
+) -> GResult<Geometry> {
+    let mut mapped = match geom.geometry_type()? {
+        GeometryTypes::Point => Geometry::create_point(f(geom.get_coord_seq()?)?)?,
+        GeometryTypes::LineString => Geometry::create_line_string(f(geom.get_coord_seq()?)?)?,
+        GeometryTypes::LinearRing => Geometry::create_linear_ring(f(geom.get_coord_seq()?)?)?,
+        GeometryTypes::Polygon => {
+            if geom.is_empty()? {
+                Geom::clone(geom)?
+            } else {
+                let exterior = map_coord_seqs(&geom.get_exterior_ring()?, f)?;
+                let interiors = (0..geom.get_num_interior_rings()?)
+                    .map(|n| map_coord_seqs(&geom.get_interior_ring_n(n)?, f))
+                    .collect::<GResult<_>>()?;
+                Geometry::create_polygon(exterior, interiors)?
+            }
+        }
+        t if t.is_collection() => {
+            let members = (0..geom.get_num_geometries()?)
+                .map(|n| map_coord_seqs(&geom.get_geometry_n(n)?, f))
+                .collect::<GResult<_>>()?;
+            create_multi_geom(members, t)?
+        }
+        t => {
+            return Err(Error::ImpossibleOperation(format!(
+                "cannot rebuild the coordinates of a {t:?}"
+            )))
+        }
+    };
+    mapped.set_srid(geom.get_srid()?);
+    Ok(mapped)
+}
+
+/// Returns the X and Y of every vertex of `geom`, in the order of [`for_each_coord_seq`].
+pub fn collect_xy<G: Geom>(geom: &G) -> GResult<Vec<(f64, f64)>> {
+    let mut vertices = Vec::new();
+    for_each_coord_seq(geom, &mut |coords| {
+        for line in 0..coords.size()? {
+            vertices.push((coords.get_x(line)?, coords.get_y(line)?));
+        }
+        Ok(())
+    })?;
+    Ok(vertices)
+}
+
+/// Checks that `a` and `b` have the same geometry types, number of parts and number of holes,
+/// without looking at their coordinates.
+pub fn same_structure<G: Geom, H: Geom>(a: &G, b: &H) -> GResult<bool> {
+    let geom_type = a.geometry_type()?;
+    if geom_type != b.geometry_type()? || a.is_empty()? != b.is_empty()? {
+        return Ok(false);
+    }
+    if geom_type == GeometryTypes::Polygon {
+        return Ok(a.is_empty()? || a.get_num_interior_rings()? == b.get_num_interior_rings()?);
+    }
+    if geom_type.is_collection() {
+        let count = a.get_num_geometries()?;
+        if count != b.get_num_geometries()? {
+            return Ok(false);
+        }
+        for n in 0..count {
+            if !same_structure(&a.get_geometry_n(n)?, &b.get_geometry_n(n)?)? {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Orders `(x, y)` pairs by X, then by Y.
+pub fn cmp_xy(a: &(f64, f64), b: &(f64, f64)) -> std::cmp::Ordering {
+    a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1))
+}
+
+/// Returns the nodes [`Geom::node`] adds to `linework`, which are the points where it crosses or
+/// touches itself outside of its vertices. Each node is returned once, sorted with [`cmp_xy`].
+pub fn added_nodes<G: Geom>(linework: &G) -> GResult<Vec<(f64, f64)>> {
+    let mut vertices = collect_xy(linework)?;
+    vertices.sort_by(cmp_xy);
+    let mut nodes = collect_xy(&linework.node()?)?;
+    nodes.sort_by(cmp_xy);
+    nodes.dedup();
+    nodes.retain(|node| vertices.binary_search_by(|v| cmp_xy(v, node)).is_err());
+    Ok(nodes)
+}
+
+/// Calls `GEOSMinimumBoundingCircle_r` on `geom` and returns the center of the circle, as a
+/// point, and its radius. The circle polygon itself is freed right away.
+#[cfg(feature = "v3_8_0")]
+pub fn minimum_bounding_circle<G: Geom>(geom: &G) -> GResult<(Geometry, f64)> {
+    with_context(|ctx| unsafe {
+        let mut radius = 0.;
+        let mut center: *mut GEOSGeometry = std::ptr::null_mut();
+        let ptr = nullcheck!(GEOSMinimumBoundingCircle_r(
+            ctx.as_raw(),
+            geom.as_raw(),
+            &mut radius,
+            &mut center,
+        ))?;
+        GEOSGeom_destroy_r(ctx.as_raw(), ptr.as_ptr());
+        let Some(center) = NonNull::new(center) else {
+            return Err(Error::GeosError((
+                "GEOSMinimumBoundingCircle_r",
+                ctx.get_last_error(),
+            )));
+        };
+        Ok((Geometry::new_from_raw(center), radius))
+    })
+}
+
 pub fn orientation_index(
     ax: f64,
     ay: f64,
@@ -9,9 +9,10 @@ use crate::GeoJSONWriter;
 use crate::MakeValidParams;
 #[cfg(feature = "v3_6_0")]
 use crate::Precision;
-use crate::{AsRaw, AsRawMut, BufferParams, CoordSeq, PreparedGeometry, WKTWriter};
+use crate::{AsRaw, AsRawMut, BufferParams, CoordSeq, PreparedGeometry, PreparedMask, WKTWriter};
 use geos_sys::*;
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::ffi::CString;
 use std::marker::PhantomData;
 use std::ptr::NonNull;
@@ -121,6 +122,40 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Checks the validity of each top-level member of `self` and returns, for each of them, its
+    /// index, whether it's valid and, if it isn't, the [reason](Geom::is_valid_reason) why.
+    ///
+    /// A non-collection geometry is handled as a collection with a single member.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "MULTIPOLYGON(((0 0, 1 0, 1 1, 0 0)), ((0 0, 2 2, 2 0, 0 2, 0 0)))",
+    /// )?;
+    /// let results = geom.validate_members()?;
+    ///
+    /// assert_eq!(results[0], (0, true, None));
+    /// assert_eq!(results[1].0, 1);
+    /// assert_eq!(results[1].1, false);
+    /// assert_eq!(results[1].2.as_deref(), Some("Self-intersection[1 1]"));
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn validate_members(&self) -> GResult<Vec<(usize, bool, Option<String>)>> {
+        (0..self.get_num_geometries()?)
+            .map(|n| {
+                let member = self.get_geometry_n(n)?;
+                if member.is_valid()? {
+                    Ok((n, true, None))
+                } else {
+                    Ok((n, false, Some(member.is_valid_reason()?)))
+                }
+            })
+            .collect()
+    }
+
     /// Get the underlying geos `CoordSeq` object from the geometry
     ///
     /// Note: this clones the underlying `CoordSeq` to avoid double free
@@ -460,6 +495,84 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Checks if the normalized forms of the two geometries have the same WKB representation.
+    ///
+    /// Unlike [`equals`](Geom::equals), this isn't a topological comparison: the geometries must
+    /// share the same vertices and structure, only their order (ring start point, orientation,
+    /// order of the parts) may differ. `LINESTRING(0 0, 2 0)` and `LINESTRING(0 0, 1 0, 2 0)` are
+    /// topologically equal but not structurally.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 1 0, 1 1, 0 1, 0 0))")?;
+    /// let geom2 = Geometry::new_from_wkt("POLYGON((1 1, 1 0, 0 0, 0 1, 1 1))")?;
+    /// let geom3 = Geometry::new_from_wkt("POLYGON((0 0, 0.5 0, 1 0, 1 1, 0 1, 0 0))")?;
+    ///
+    /// assert_eq!(geom1.equals_normalized(&geom2)?, true);
+    /// assert_eq!(geom1.equals_normalized(&geom3)?, false);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn equals_normalized<G: Geom>(&self, other: &G) -> GResult<bool> {
+        let mut geom1 = Geom::clone(self)?;
+        let mut geom2 = Geom::clone(other)?;
+        geom1.normalize()?;
+        geom2.normalize()?;
+        Ok(geom1.to_wkb()? == geom2.to_wkb()?)
+    }
+
+    /// Checks if the normalized forms of the two geometries have the same structure and
+    /// coordinates differing by at most `tol_x` along the X axis and `tol_y` along the Y axis.
+    ///
+    /// As for [`equals_normalized`](Geom::equals_normalized), this isn't a topological
+    /// comparison. Only X and Y are compared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("LINESTRING(1000000 0.5, 2000000 0.5)")?;
+    /// let geom2 = Geometry::new_from_wkt("LINESTRING(2000000.1 0.5, 1000000.1 0.5)")?;
+    ///
+    /// assert_eq!(geom1.approx_equal(&geom2, 0.5, 1e-9)?, true);
+    /// assert_eq!(geom1.approx_equal(&geom2, 0.01, 1e-9)?, false);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn approx_equal<G: Geom>(&self, other: &G, tol_x: f64, tol_y: f64) -> GResult<bool> {
+        let mut geom1 = Geom::clone(self)?;
+        let mut geom2 = Geom::clone(other)?;
+        geom1.normalize()?;
+        geom2.normalize()?;
+        if !same_structure(&geom1, &geom2)? {
+            return Ok(false);
+        }
+
+        let mut sequences = [Vec::new(), Vec::new()];
+        for (geom, sequences) in [&geom1, &geom2].into_iter().zip(&mut sequences) {
+            for_each_coord_seq(geom, &mut |coords| {
+                let coords = (0..coords.size()?)
+                    .map(|line| Ok((coords.get_x(line)?, coords.get_y(line)?)))
+                    .collect::<GResult<Vec<_>>>()?;
+                sequences.push(coords);
+                Ok(())
+            })?;
+        }
+        let [sequences1, sequences2] = sequences;
+        Ok(sequences1.len() == sequences2.len()
+            && sequences1
+                .iter()
+                .zip(&sequences2)
+                .all(|(coords1, coords2)| {
+                    coords1.len() == coords2.len()
+                        && coords1.iter().zip(coords2).all(|((x1, y1), (x2, y2))| {
+                            (x1 - x2).abs() <= tol_x && (y1 - y2).abs() <= tol_y
+                        })
+                }))
+    }
+
     #[cfg(feature = "v3_12_0")]
     fn equals_identical<G: Geom>(&self, other: &G) -> GResult<bool> {
         with_context(|ctx| unsafe {
@@ -682,6 +795,111 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns a buffer of `self` whose distance varies along the line: `distances` gives the
+    /// distance at each vertex, and it's interpolated linearly along each segment.
+    ///
+    /// Each segment is buffered as the convex hull of the circles around its two vertices, using
+    /// 8 segments per quadrant, and the pieces are unioned.
+    ///
+    /// `self` must be a `LineString` and `distances` must have one positive (or zero) distance
+    /// per vertex, otherwise it'll fail. A segment whose two distances are zero has no area and is
+    /// left out, so the result is always a (possibly empty) polygon or multipolygon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0)")?;
+    /// let buffer = line.variable_buffer(&[1., 3.])?;
+    ///
+    /// let near_start = Geometry::new_from_wkt("POINT(0 2)")?;
+    /// let near_end = Geometry::new_from_wkt("POINT(10 2)")?;
+    /// assert!(!buffer.contains(&near_start)?);
+    /// assert!(buffer.contains(&near_end)?);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn variable_buffer(&self, distances: &[f64]) -> GResult<Geometry> {
+        if self.geometry_type()? != GeometryTypes::LineString {
+            return Err(Error::ImpossibleOperation(
+                "variable buffer is only available on LineString".to_owned(),
+            ));
+        }
+        let vertices = collect_xy(self)?;
+        if distances.len() != vertices.len() {
+            return Err(Error::GenericError(format!(
+                "expected {} distances, one per vertex, got {}",
+                vertices.len(),
+                distances.len()
+            )));
+        }
+        if distances.iter().any(|d| !d.is_finite() || *d < 0.) {
+            return Err(Error::GenericError(
+                "distances must be positive numbers".to_owned(),
+            ));
+        }
+        let circles = vertices
+            .iter()
+            .zip(distances)
+            .map(|(&(x, y), &distance)| {
+                let point = Geometry::create_point(CoordSeq::new_from_vec(&[&[x, y]])?)?;
+                // Buffering by 0 gives an empty polygon, the point itself is kept instead.
+                if distance > 0. {
+                    point.buffer(distance, 8)
+                } else {
+                    Ok(point)
+                }
+            })
+            .collect::<GResult<Vec<_>>>()?;
+        let mut pieces = Vec::with_capacity(circles.len().saturating_sub(1));
+        for pair in circles.windows(2) {
+            let pair = vec![Geom::clone(&pair[0])?, Geom::clone(&pair[1])?];
+            let hull = Geometry::create_geometry_collection(pair)?.convex_hull()?;
+            // The hull of two points is a line (or a point), it's not part of the buffer.
+            if hull.geometry_type()? == GeometryTypes::Polygon {
+                pieces.push(hull);
+            }
+        }
+        if pieces.is_empty() {
+            return Geometry::create_empty_polygon();
+        }
+        Geometry::create_geometry_collection(pieces)?.unary_union()
+    }
+
+    /// Returns the area whose distance to `self` is between `inner` and `outer`, which is the
+    /// [`buffer`](Geom::buffer) of `self` at `outer` minus its buffer at `inner`.
+    ///
+    /// With an `inner` distance of `0`, the band starts right at `self`. `outer` must be greater
+    /// than `inner`, and `inner` positive, otherwise it'll fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))")?;
+    /// let band = geom.buffer_ring(1., 2., 8)?;
+    ///
+    /// assert_eq!(band.get_num_interior_rings()?, 1);
+    /// let point = Geometry::new_from_wkt("POINT(3.5 1)")?;
+    /// assert!(band.contains(&point)?);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn buffer_ring(&self, inner: f64, outer: f64, quad_segs: i32) -> GResult<Geometry> {
+        if !inner.is_finite() || inner < 0. {
+            return Err(Error::GenericError(
+                "inner must be a positive number".to_owned(),
+            ));
+        }
+        if !outer.is_finite() || outer <= inner {
+            return Err(Error::GenericError(
+                "outer must be greater than inner".to_owned(),
+            ));
+        }
+        self.buffer(outer, quad_segs)?
+            .difference(&self.buffer(inner, quad_segs)?)
+    }
+
     /// Returns `true` if the given geometry is empty.
     ///
     /// # Example
@@ -889,6 +1107,30 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns the distance between the [centroids](Geom::get_centroid) of `self` and `other`.
+    ///
+    /// It fails if one of them is empty, as its centroid is undefined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))")?;
+    /// let geom2 = Geometry::new_from_wkt("LINESTRING(4 1, 6 1)")?;
+    ///
+    /// assert_eq!(geom1.centroid_distance(&geom2)?, 4.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn centroid_distance<G: Geom>(&self, other: &G) -> GResult<f64> {
+        if self.is_empty()? || other.is_empty()? {
+            return Err(Error::ImpossibleOperation(
+                "the centroid of an empty geometry is undefined".to_owned(),
+            ));
+        }
+        self.get_centroid()?.distance(&other.get_centroid()?)
+    }
+
     /// Documentation from [postgis](https://postgis.net/docs/ST_UnaryUnion.html):
     ///
     /// > Unlike ST_Union, ST_UnaryUnion does dissolve boundaries between components of a
@@ -942,6 +1184,145 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Checks if `self`, a collection of polygons, forms a valid polygonal coverage: members
+    /// must not overlap and must share matching vertices along their common edges. Gaps narrower
+    /// than `gap_width` are reported as invalid as well.
+    ///
+    /// Returns, in the tuple elements order:
+    ///
+    /// 1. Whether the coverage is valid.
+    /// 2. A collection with, for each member, the invalid edges found (or an empty geometry).
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let coverage = Geometry::new_from_wkt(
+    ///     "GEOMETRYCOLLECTION(POLYGON((0 0, 1 0, 1 1, 0 1, 0 0)), POLYGON((1 0, 2 0, 2 1, 1 1, 1 0)))",
+    /// )?;
+    /// let (is_valid, _) = coverage.coverage_is_valid(0.)?;
+    /// assert_eq!(is_valid, true);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_12_0")]
+    fn coverage_is_valid(&self, gap_width: f64) -> GResult<(bool, Geometry)> {
+        with_context(|ctx| unsafe {
+            let mut invalid_edges: *mut GEOSGeometry = std::ptr::null_mut();
+            let ret = errcheck!(
+                2,
+                GEOSCoverageIsValid_r(ctx.as_raw(), self.as_raw(), gap_width, &mut invalid_edges)
+            )?;
+            let invalid_edges = NonNull::new(invalid_edges)
+                .ok_or_else(|| Error::GeosError(("GEOSCoverageIsValid_r", ctx.get_last_error())))?;
+            Ok((ret == 1, Geometry::new_from_raw(invalid_edges)))
+        })
+    }
+
+    /// Attempts to turn `self`, a slightly invalid polygonal coverage, into a valid one.
+    ///
+    /// If [`coverage_is_valid`](crate::Geom::coverage_is_valid) already succeeds, a copy of
+    /// `self` is returned. Otherwise each member is snapped in turn to the other (partially
+    /// fixed) members, using `gap_width` as the snapping tolerance, and the result is checked
+    /// again.
+    ///
+    /// This is a best-effort repair: it only handles overlaps and gaps narrower than `gap_width`
+    /// and returns an error if the snapped coverage is still invalid.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let coverage = Geometry::new_from_wkt(
+    ///     "GEOMETRYCOLLECTION(POLYGON((0 0, 1 0, 1 1, 0 1, 0 0)), \
+    ///                         POLYGON((0.999 0, 2 0, 2 1, 0.999 1, 0.999 0)))",
+    /// )?;
+    /// assert_eq!(coverage.coverage_is_valid(0.01)?.0, false);
+    ///
+    /// let fixed = coverage.fix_coverage(0.01)?;
+    /// assert_eq!(fixed.coverage_is_valid(0.01)?.0, true);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_12_0")]
+    fn fix_coverage(&self, gap_width: f64) -> GResult<Geometry> {
+        if self.coverage_is_valid(gap_width)?.0 {
+            return Geom::clone(self);
+        }
+
+        let output_type = match self.geometry_type()? {
+            GeometryTypes::MultiPolygon => GeometryTypes::MultiPolygon,
+            _ => GeometryTypes::GeometryCollection,
+        };
+        let mut members = (0..self.get_num_geometries()?)
+            .map(|n| self.get_geometry_n(n)?.clone())
+            .collect::<GResult<Vec<_>>>()?;
+
+        for n in 0..members.len() {
+            let others = members
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != n)
+                .map(|(_, g)| Geom::clone(g))
+                .collect::<GResult<Vec<_>>>()?;
+            let others = create_multi_geom(others, GeometryTypes::GeometryCollection)?;
+            members[n] = members[n].snap(&others, gap_width)?;
+        }
+
+        let fixed = create_multi_geom(members, output_type)?;
+        if fixed.coverage_is_valid(gap_width)?.0 {
+            Ok(fixed)
+        } else {
+            Err(Error::GenericError(
+                "coverage could not be made valid by snapping".to_owned(),
+            ))
+        }
+    }
+
+    /// Same as [`coverage_union`](Geom::coverage_union), but checks first that `self` is a valid
+    /// coverage with [`coverage_is_valid`](Geom::coverage_is_valid), `gap_width` being used for
+    /// the check. If it isn't, an error listing the invalid edges of each member is returned
+    /// instead of an incorrect union.
+    ///
+    /// The validation is usually more expensive than the union itself.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let coverage = Geometry::new_from_wkt(
+    ///     "GEOMETRYCOLLECTION(POLYGON((0 0, 1 0, 1 1, 0 1, 0 0)), \
+    ///                         POLYGON((0.5 0, 2 0, 2 1, 0.5 1, 0.5 0)))",
+    /// )?;
+    /// assert!(coverage.coverage_union_checked(0.).is_err());
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_12_0")]
+    fn coverage_union_checked(&self, gap_width: f64) -> GResult<Geometry> {
+        let (is_valid, invalid_edges) = self.coverage_is_valid(gap_width)?;
+        if !is_valid {
+            let mut members = Vec::new();
+            for n in 0..invalid_edges.get_num_geometries()? {
+                let edges = invalid_edges.get_geometry_n(n)?;
+                if !edges.is_empty()? {
+                    members.push(format!("member {n}: {}", edges.to_wkt()?));
+                }
+            }
+            return Err(Error::GenericError(format!(
+                "invalid coverage, found invalid edges on {}",
+                members.join(", ")
+            )));
+        }
+        self.coverage_union()
+    }
+
     #[cfg(feature = "v3_12_0")]
     fn disjoint_subset_union(&self) -> GResult<Geometry> {
         with_context(|ctx| unsafe {
@@ -1034,6 +1415,159 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Computes the given overlay `op` of `self` and `other`. When `grid_size` is provided, the
+    /// result is snapped to a grid of that size by the `*_prec` variant of the operation.
+    ///
+    /// Available using the `v3_9_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, OverlayOp};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("LINESTRING(0 0, 10 0)")?;
+    /// let geom2 = Geometry::new_from_wkt("LINESTRING(4.9 0, 20.2 0)")?;
+    ///
+    /// let overlay = geom1.overlay(&geom2, OverlayOp::Intersection, None)?;
+    /// assert_eq!(overlay.to_wkt()?, "LINESTRING (4.9 0, 10 0)");
+    ///
+    /// let overlay = geom1.overlay(&geom2, OverlayOp::Intersection, Some(1.))?;
+    /// assert_eq!(overlay.to_wkt()?, "LINESTRING (5 0, 10 0)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_9_0")]
+    fn overlay<G: Geom>(
+        &self,
+        other: &G,
+        op: OverlayOp,
+        grid_size: Option<f64>,
+    ) -> GResult<Geometry> {
+        match (op, grid_size) {
+            (OverlayOp::Intersection, None) => self.intersection(other),
+            (OverlayOp::Intersection, Some(grid_size)) => self.intersection_prec(other, grid_size),
+            (OverlayOp::Union, None) => self.union(other),
+            (OverlayOp::Union, Some(grid_size)) => self.union_prec(other, grid_size),
+            (OverlayOp::Difference, None) => self.difference(other),
+            (OverlayOp::Difference, Some(grid_size)) => self.difference_prec(other, grid_size),
+            (OverlayOp::SymDifference, None) => self.sym_difference(other),
+            (OverlayOp::SymDifference, Some(grid_size)) => {
+                self.sym_difference_prec(other, grid_size)
+            }
+        }
+    }
+
+    /// Clips every line of `self` to `mask` separately and returns the inside portions with the
+    /// index of the line they come from. Lines that don't enter `mask` (or only touch it) are
+    /// skipped.
+    ///
+    /// Every inside portion is returned as its own `LineString`: a line which enters `mask`
+    /// several times gives several pairs with the same index.
+    ///
+    /// `self` must be a `LineString`, `LinearRing` or `MultiLineString`, otherwise it'll fail. A
+    /// single line has the index `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let lines = Geometry::new_from_wkt("MULTILINESTRING((-5 1, 5 1), (-5 5, 5 5))")?;
+    /// let mask = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))")?;
+    ///
+    /// let clipped = lines.clip_lines(&mask)?;
+    ///
+    /// assert_eq!(clipped.len(), 1);
+    /// assert_eq!(clipped[0].0, 0);
+    /// assert_eq!(clipped[0].1.to_wkt()?, "LINESTRING (0 1, 2 1)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn clip_lines<G: Geom>(&self, mask: &G) -> GResult<Vec<(usize, Geometry)>> {
+        let mut clipped = Vec::new();
+        let mut push = |index: usize, clip: Geometry| -> GResult<()> {
+            let parts = if clip.geometry_type()?.is_collection() {
+                (0..clip.get_num_geometries()?)
+                    .map(|n| Geom::clone(&clip.get_geometry_n(n)?))
+                    .collect::<GResult<Vec<_>>>()?
+            } else {
+                vec![clip]
+            };
+            for part in parts {
+                // Lines only touching the mask give points, they aren't inside portions.
+                if part.geometry_type()? == GeometryTypes::LineString && !part.is_empty()? {
+                    clipped.push((index, part));
+                }
+            }
+            Ok(())
+        };
+        match self.geometry_type()? {
+            GeometryTypes::LineString | GeometryTypes::LinearRing => {
+                push(0, self.intersection(mask)?)?;
+            }
+            GeometryTypes::MultiLineString => {
+                for n in 0..self.get_num_geometries()? {
+                    push(n, self.get_geometry_n(n)?.intersection(mask)?)?;
+                }
+            }
+            _ => {
+                return Err(Error::ImpossibleOperation(
+                    "clipping lines is only possible on linear geometries".to_owned(),
+                ))
+            }
+        }
+        Ok(clipped)
+    }
+
+    /// Returns the area of the part of `self` inside `mask`, which is the area of their
+    /// [`intersection`](Geom::intersection). It returns `0.0` if they don't intersect.
+    ///
+    /// When the same mask is used many times, [`area_within_prepared`](Geom::area_within_prepared)
+    /// is faster.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 4 0, 4 4, 0 4, 0 0))")?;
+    /// let mask = Geometry::new_from_wkt("POLYGON((1 1, 5 1, 5 5, 1 5, 1 1))")?;
+    ///
+    /// assert_eq!(geom.area_within(&mask)?, 9.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn area_within<G: Geom>(&self, mask: &G) -> GResult<f64> {
+        self.intersection(mask)?.area()
+    }
+
+    /// Same as [`area_within`](Geom::area_within), with the mask and its
+    /// [`PreparedGeometry`] taken from a [`PreparedMask`]. The intersection is only computed
+    /// when `self` is partly inside the mask: the prepared mask quickly tells when `self` is
+    /// fully inside or outside of it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, PreparedMask};
+    ///
+    /// let mask = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))")?;
+    /// let prepared_mask = PreparedMask::new(&mask)?;
+    ///
+    /// let inside = Geometry::new_from_wkt("POLYGON((1 1, 3 1, 3 3, 1 3, 1 1))")?;
+    /// let outside = Geometry::new_from_wkt("POLYGON((11 1, 13 1, 13 3, 11 3, 11 1))")?;
+    ///
+    /// assert_eq!(inside.area_within_prepared(&prepared_mask)?, 4.);
+    /// assert_eq!(outside.area_within_prepared(&prepared_mask)?, 0.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn area_within_prepared<G: Geom>(&self, mask: &PreparedMask<'_, G>) -> GResult<f64> {
+        if mask.prepared().covers(self)? {
+            self.area()
+        } else if !mask.prepared().intersects(self)? {
+            Ok(0.)
+        } else {
+            self.area_within(mask.geometry())
+        }
+    }
+
     /// Documentation from [postgis](https://postgis.net/docs/ST_ConvexHull.html):
     ///
     /// > The convex hull of a geometry represents the minimum convex geometry that encloses all
@@ -1073,40 +1607,331 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
-    /// Returns the closure of the combinatorial boundary of `self`.
+    /// Returns the [convex hull](Geom::convex_hull) of `self` along with a `MultiPoint` of its
+    /// corners, which are the vertices of `self` the hull goes through.
+    ///
+    /// The closing vertex of the hull ring isn't repeated in the corners. For degenerate inputs,
+    /// the hull is a `LineString` or a `Point` and its corners are its vertices.
     ///
     /// # Example
     ///
     /// ```
-    /// use geos::{Geom, Geometry, GeometryTypes};
+    /// use geos::{Geom, Geometry};
     ///
-    /// let geom = Geometry::new_from_wkt("LINESTRING(1 1,0 0, -1 1)")?;
-    /// let boundary_geom = geom.boundary()?;
-    /// assert_eq!(boundary_geom.geometry_type()?, GeometryTypes::MultiPoint);
-    /// assert_eq!(boundary_geom.get_num_geometries()?, 2);
-    /// assert_eq!(boundary_geom.get_geometry_n(0)?.to_wkt()?, "POINT (1 1)");
-    /// assert_eq!(boundary_geom.get_geometry_n(1)?.to_wkt()?, "POINT (-1 1)");
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT((0 0), (4 0), (1 1), (4 4), (0 4))")?;
+    /// let (hull, corners) = geom.convex_hull_vertices()?;
+    ///
+    /// assert_eq!(hull.get_num_coordinates()?, 5);
+    /// assert_eq!(corners.get_num_geometries()?, 4);
     /// # Ok::<(), geos::Error>(())
     /// ```
-    fn boundary(&self) -> GResult<Geometry> {
-        with_context(|ctx| unsafe {
-            let ptr = nullcheck!(GEOSBoundary_r(ctx.as_raw(), self.as_raw()))?;
-            Ok(Geometry::new_from_raw(ptr))
-        })
+    fn convex_hull_vertices(&self) -> GResult<(Geometry, Geometry)> {
+        let hull = self.convex_hull()?;
+        let mut vertices = collect_xy(&hull)?;
+        if hull.geometry_type()? == GeometryTypes::Polygon {
+            vertices.pop();
+        }
+        let corners = vertices
+            .into_iter()
+            .map(|(x, y)| Geometry::create_point(CoordSeq::new_from_vec(&[&[x, y]])?))
+            .collect::<GResult<Vec<_>>>()?;
+        Ok((hull, Geometry::create_multipoint(corners)?))
     }
 
-    /// Returns `true` if `self` has a Z coordinate.
+    /// Returns the solidity of `self`, which is the ratio between its area and the area of its
+    /// convex hull, clamped to `[0, 1]`. A convex polygon has a solidity of `1`.
+    ///
+    /// Fails if `self` has no area.
     ///
     /// # Example
     ///
     /// ```
     /// use geos::{Geom, Geometry};
     ///
-    /// let geom = Geometry::new_from_wkt("POINT(1 2 3)")?;
-    /// assert_eq!(geom.has_z()?, true);
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 1 1, 0 2, 0 0))")?;
+    /// assert_eq!(geom.solidity()?, 0.75);
     ///
-    /// let geom = Geometry::new_from_wkt("POINT(1 2)")?;
-    /// assert_eq!(geom.has_z()?, false);
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 1 1)")?;
+    /// assert!(line.solidity().is_err());
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn solidity(&self) -> GResult<f64> {
+        let area = self.area()?;
+        if area <= 0. {
+            return Err(Error::ImpossibleOperation(
+                "solidity is undefined for geometries without area".to_owned(),
+            ));
+        }
+        let hull_area = self.convex_hull()?.area()?;
+        Ok((area / hull_area).clamp(0., 1.))
+    }
+
+    /// Returns `true` if `self` is convex, meaning its area is equal to the area of its
+    /// [convex hull](Geom::convex_hull) (up to a relative tolerance of `1e-9`). A polygon with
+    /// holes is never convex.
+    ///
+    /// Only works on `Polygon`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))")?;
+    /// assert_eq!(geom.is_convex()?, true);
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 1, 1 1, 1 2, 0 2, 0 0))")?;
+    /// assert_eq!(geom.is_convex()?, false);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn is_convex(&self) -> GResult<bool> {
+        if self.geometry_type()? != GeometryTypes::Polygon {
+            return Err(Error::ImpossibleOperation(
+                "convexity can only be checked on polygons".to_owned(),
+            ));
+        }
+        if self.get_num_interior_rings()? > 0 {
+            return Ok(false);
+        }
+        let hull_area = self.convex_hull()?.area()?;
+        Ok(hull_area - self.area()? <= hull_area * 1e-9)
+    }
+
+    /// Returns `true` if `self` is an axis-aligned rectangle: a polygon without holes whose
+    /// exterior ring has exactly 4 distinct vertices, located on the corners of its envelope (up
+    /// to a relative tolerance of `1e-9`). Rotated rectangles return `false`.
+    ///
+    /// Only works on `Polygon`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 0 2, 3 2, 3 0, 0 0))")?;
+    /// assert_eq!(geom.is_rectangle()?, true);
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((1 0, 2 1, 1 2, 0 1, 1 0))")?;
+    /// assert_eq!(geom.is_rectangle()?, false);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn is_rectangle(&self) -> GResult<bool> {
+        if self.geometry_type()? != GeometryTypes::Polygon {
+            return Err(Error::ImpossibleOperation(
+                "only polygons can be rectangles".to_owned(),
+            ));
+        }
+        if self.is_empty()? || self.get_num_interior_rings()? != 0 {
+            return Ok(false);
+        }
+        let vertices = collect_xy(&self.get_exterior_ring()?)?;
+        if vertices.len() != 5 {
+            return Ok(false);
+        }
+        let (mut xmin, mut ymin) = vertices[0];
+        let (mut xmax, mut ymax) = vertices[0];
+        for &(x, y) in &vertices {
+            xmin = xmin.min(x);
+            ymin = ymin.min(y);
+            xmax = xmax.max(x);
+            ymax = ymax.max(y);
+        }
+        let (width, height) = (xmax - xmin, ymax - ymin);
+        let tolerance = width.max(height) * 1e-9;
+        let on_corner = |&(x, y): &(f64, f64)| {
+            ((x - xmin).abs() <= tolerance || (x - xmax).abs() <= tolerance)
+                && ((y - ymin).abs() <= tolerance || (y - ymax).abs() <= tolerance)
+        };
+        // With all its vertices on the corners, only a rectangle covers the whole envelope.
+        Ok(width > 0.
+            && height > 0.
+            && vertices.iter().all(on_corner)
+            && (self.area()? - width * height).abs() <= width * height * 1e-9)
+    }
+
+    /// Returns a copy of the largest top-level member of `self`.
+    ///
+    /// Members are compared by [`area`](Geom::area) if `self` has a surface dimension, by
+    /// [`length`](Geom::length) if it has a curve dimension. For points, the first member is
+    /// returned. On ties, the first member wins. It fails if `self` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "MULTIPOLYGON(((0 0, 1 0, 1 1, 0 1, 0 0)), ((5 5, 8 5, 8 8, 5 8, 5 5)))",
+    /// )?;
+    /// let largest = geom.largest_part()?;
+    ///
+    /// assert_eq!(largest.to_wkt()?, "POLYGON ((5 5, 8 5, 8 8, 5 8, 5 5))");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn largest_part(&self) -> GResult<Geometry> {
+        if self.is_empty()? {
+            return Err(Error::ImpossibleOperation(
+                "cannot get the largest part of an empty geometry".to_owned(),
+            ));
+        }
+        let dimension = self.get_dimension()?;
+        let measure = |geom: &ConstGeometry| match dimension {
+            DimensionType::Surface => geom.area(),
+            DimensionType::Curve => geom.length(),
+            DimensionType::Point => Ok(0.),
+        };
+        let mut largest = self.get_geometry_n(0)?;
+        let mut largest_measure = measure(&largest)?;
+        for n in 1..self.get_num_geometries()? {
+            let member = self.get_geometry_n(n)?;
+            let member_measure = measure(&member)?;
+            if member_measure > largest_measure {
+                largest = member;
+                largest_measure = member_measure;
+            }
+        }
+        largest.clone()
+    }
+
+    /// Returns a copy of `self` where the holes with an area smaller than `max_area` are filled.
+    /// Larger holes are kept.
+    ///
+    /// Only works on `Polygon` and `MultiPolygon`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "POLYGON((0 0, 10 0, 10 10, 0 10, 0 0), (1 1, 1.1 1, 1.1 1.1, 1 1), \
+    ///                                               (5 5, 8 5, 8 8, 5 8, 5 5))",
+    /// )?;
+    /// let filled = geom.fill_small_holes(1.)?;
+    ///
+    /// assert_eq!(
+    ///     filled.to_wkt()?,
+    ///     "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (5 5, 8 5, 8 8, 5 8, 5 5))"
+    /// );
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn fill_small_holes(&self, max_area: f64) -> GResult<Geometry> {
+        fn fill_polygon<G: Geom>(polygon: &G, max_area: f64) -> GResult<Geometry> {
+            if polygon.is_empty()? {
+                return Geom::clone(polygon);
+            }
+            let mut interiors = Vec::new();
+            for n in 0..polygon.get_num_interior_rings()? {
+                let ring = polygon.get_interior_ring_n(n)?.clone()?;
+                let hole = Geometry::create_polygon(ring, vec![])?;
+                if hole.area()? >= max_area {
+                    interiors.push(hole.get_exterior_ring()?.clone()?);
+                }
+            }
+            Geometry::create_polygon(polygon.get_exterior_ring()?.clone()?, interiors)
+        }
+
+        match self.geometry_type()? {
+            GeometryTypes::Polygon => fill_polygon(self, max_area),
+            GeometryTypes::MultiPolygon => Geometry::create_multipolygon(
+                (0..self.get_num_geometries()?)
+                    .map(|n| fill_polygon(&self.get_geometry_n(n)?, max_area))
+                    .collect::<GResult<_>>()?,
+            ),
+            _ => Err(Error::ImpossibleOperation(
+                "holes can only be filled in polygons".to_owned(),
+            )),
+        }
+    }
+
+    /// Returns each interior ring of `self` as a polygon.
+    ///
+    /// Only works on `Polygon`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "POLYGON((0 0, 10 0, 10 10, 0 10, 0 0), (1 1, 2 1, 2 2, 1 1))",
+    /// )?;
+    /// let holes = geom.holes_as_polygons()?;
+    ///
+    /// assert_eq!(holes.len(), 1);
+    /// assert_eq!(holes[0].to_wkt()?, "POLYGON ((1 1, 2 1, 2 2, 1 1))");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn holes_as_polygons(&self) -> GResult<Vec<Geometry>> {
+        if self.geometry_type()? != GeometryTypes::Polygon {
+            return Err(Error::ImpossibleOperation(
+                "only polygons have holes".to_owned(),
+            ));
+        }
+        if self.is_empty()? {
+            return Ok(Vec::new());
+        }
+        (0..self.get_num_interior_rings()?)
+            .map(|n| Geometry::create_polygon(self.get_interior_ring_n(n)?.clone()?, vec![]))
+            .collect()
+    }
+
+    /// Returns the closure of the combinatorial boundary of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, GeometryTypes};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(1 1,0 0, -1 1)")?;
+    /// let boundary_geom = geom.boundary()?;
+    /// assert_eq!(boundary_geom.geometry_type()?, GeometryTypes::MultiPoint);
+    /// assert_eq!(boundary_geom.get_num_geometries()?, 2);
+    /// assert_eq!(boundary_geom.get_geometry_n(0)?.to_wkt()?, "POINT (1 1)");
+    /// assert_eq!(boundary_geom.get_geometry_n(1)?.to_wkt()?, "POINT (-1 1)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn boundary(&self) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSBoundary_r(ctx.as_raw(), self.as_raw()))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    /// Returns the length of the part of the [`boundary`](Geom::boundary) of `self` which is
+    /// shared with the boundary of `other`.
+    ///
+    /// Geometries only touching at some points return `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))")?;
+    /// let geom2 = Geometry::new_from_wkt("POLYGON((2 1, 3 1, 3 4, 2 4, 2 1))")?;
+    /// let geom3 = Geometry::new_from_wkt("POLYGON((2 2, 3 2, 3 3, 2 2))")?;
+    ///
+    /// assert_eq!(geom1.shared_boundary_length(&geom2)?, 1.);
+    /// assert_eq!(geom1.shared_boundary_length(&geom3)?, 0.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn shared_boundary_length<G: Geom>(&self, other: &G) -> GResult<f64> {
+        self.boundary()?.intersection(&other.boundary()?)?.length()
+    }
+
+    /// Returns `true` if `self` has a Z coordinate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POINT(1 2 3)")?;
+    /// assert_eq!(geom.has_z()?, true);
+    ///
+    /// let geom = Geometry::new_from_wkt("POINT(1 2)")?;
+    /// assert_eq!(geom.has_z()?, false);
     /// # Ok::<(), geos::Error>(())
     /// ```
     fn has_z(&self) -> GResult<bool> {
@@ -1132,6 +1957,46 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         with_context(|ctx| unsafe { predicate!(GEOSHasM_r(ctx.as_raw(), self.as_raw())) })
     }
 
+    /// Returns `false` if any ordinate of `self` is NaN or infinite.
+    ///
+    /// X and Y are always checked, Z only when the coordinates have one. M is checked as well
+    /// when using the `v3_14_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{CoordSeq, Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 1 0, 1 1, 0 0))")?;
+    /// assert_eq!(geom.has_finite_coordinates()?, true);
+    ///
+    /// let coords = CoordSeq::new_from_vec(&[&[0., 0.], &[f64::NAN, 1.]])?;
+    /// let geom = Geometry::create_line_string(coords)?;
+    /// assert_eq!(geom.has_finite_coordinates()?, false);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn has_finite_coordinates(&self) -> GResult<bool> {
+        let mut finite = true;
+        for_each_coord_seq(self, &mut |coords| {
+            let coord_type = coords.coord_type();
+            for line in 0..coords.size()? {
+                if !finite {
+                    break;
+                }
+                finite = coords.get_x(line)?.is_finite() && coords.get_y(line)?.is_finite();
+                if finite && coord_type.has_z() {
+                    finite = coords.get_z(line)?.is_finite();
+                }
+                #[cfg(feature = "v3_14_0")]
+                if finite && coord_type.has_m() {
+                    finite = coords.get_m(line)?.is_finite();
+                }
+            }
+            Ok(())
+        })?;
+        Ok(finite)
+    }
+
     /// Returns `true` if start and end point are coincident.
     ///
     /// Only works on `LineString`, `LinearRing`, `CircularString`, `MultiLineString` and `MultiCurve`.
@@ -1468,6 +2333,68 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Snaps the vertices of `self` to the other vertices of `self` which are closer than
+    /// `tolerance`, merging nearly-coincident vertices even when they aren't consecutive.
+    ///
+    /// Vertices are visited in order and each one is kept unless it lies within `tolerance` of an
+    /// already kept vertex, in which case it's moved onto it. [`snap`](Geom::snap) is then called
+    /// with the kept vertices as target: snapping `self` directly onto itself would put the
+    /// merged vertices back, as they lie within `tolerance` of the segments of `self`.
+    ///
+    /// Merged vertices are left in place as repeated points, and the result can be invalid
+    /// (a ring touching itself for example): use `remove_repeated_points` or `make_valid`
+    /// afterwards if needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10, 0.001 0.001)")?;
+    /// let snapped = geom.self_snap(0.01)?;
+    ///
+    /// assert_eq!(snapped.to_wkt()?, "LINESTRING (0 0, 10 0, 10 10, 0 0)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn self_snap(&self, tolerance: f64) -> GResult<Geometry> {
+        // Kept vertices are bucketed in cells of `tolerance` size, so only the cells around a
+        // vertex have to be searched.
+        let cell = |x: f64, y: f64| {
+            (
+                (x / tolerance).floor() as i64,
+                (y / tolerance).floor() as i64,
+            )
+        };
+        let mut grid: HashMap<(i64, i64), Vec<(f64, f64)>> = HashMap::new();
+        let mut kept = Vec::new();
+        for_each_coord_seq(self, &mut |coords| {
+            for line in 0..coords.size()? {
+                let (x, y) = (coords.get_x(line)?, coords.get_y(line)?);
+                let (cx, cy) = cell(x, y);
+                let near = tolerance > 0.
+                    && (cx.saturating_sub(1)..=cx.saturating_add(1)).any(|kx| {
+                        (cy.saturating_sub(1)..=cy.saturating_add(1)).any(|ky| {
+                            grid.get(&(kx, ky)).map_or(false, |vertices| {
+                                vertices
+                                    .iter()
+                                    .any(|(vx, vy)| (vx - x).hypot(vy - y) < tolerance)
+                            })
+                        })
+                    });
+                if !near {
+                    kept.push((x, y));
+                    grid.entry((cx, cy)).or_default().push((x, y));
+                }
+            }
+            Ok(())
+        })?;
+        let points = kept
+            .into_iter()
+            .map(|(x, y)| Geometry::create_point(CoordSeq::new_from_vec(&[&[x, y]])?))
+            .collect::<GResult<Vec<_>>>()?;
+        self.snap(&Geometry::create_multipoint(points)?, tolerance)
+    }
+
     /// Returns unique points of `self`.
     fn extract_unique_points(&self) -> GResult<Geometry> {
         with_context(|ctx| unsafe {
@@ -1515,6 +2442,38 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns, as points, the point of `self` nearest to `other` and the point of `other`
+    /// nearest to `self`, computed by calling [`nearest_points`](Geom::nearest_points) in both
+    /// directions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))")?;
+    /// let point = Geometry::new_from_wkt("POINT(3 1)")?;
+    ///
+    /// let (on_geom, on_point) = geom.mutual_nearest_points(&point)?;
+    /// assert_eq!(on_geom.to_wkt()?, "POINT (2 1)");
+    /// assert_eq!(on_point.to_wkt()?, "POINT (3 1)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn mutual_nearest_points<G: Geom>(&self, other: &G) -> GResult<(Geometry, Geometry)> {
+        let first = self.nearest_points(other)?;
+        let second = other.nearest_points(self)?;
+        Ok((
+            Geometry::create_point(CoordSeq::new_from_vec(&[&[
+                first.get_x(0)?,
+                first.get_y(0)?,
+            ]])?)?,
+            Geometry::create_point(CoordSeq::new_from_vec(&[&[
+                second.get_x(0)?,
+                second.get_y(0)?,
+            ]])?)?,
+        ))
+    }
+
     /// Returns the X position. The given `Geometry` must be a `Point`, otherwise it'll fail.
     ///
     /// # Example
@@ -1597,6 +2556,46 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns the given `ordinate` of the coordinate at `coord_index`.
+    ///
+    /// Only works on geometries made of a single coordinate sequence, like `Point`, `LineString`
+    /// and `LinearRing`. It fails if `coord_index` is out of bounds or if the coordinates of
+    /// `self` don't have the requested ordinate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, Ordinate};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING Z(1 2 3, 4 5 6)")?;
+    ///
+    /// assert_eq!(geom.get_ordinate(1, Ordinate::Y)?, 5.);
+    /// assert_eq!(geom.get_ordinate(1, Ordinate::Z)?, 6.);
+    /// assert!(geom.get_ordinate(2, Ordinate::X).is_err());
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn get_ordinate(&self, coord_index: usize, ordinate: Ordinate) -> GResult<f64> {
+        let coords = self.get_coord_seq()?;
+        let size = coords.size()?;
+        if coord_index >= size {
+            return Err(Error::GenericError(format!(
+                "coordinate index {coord_index} is out of bounds, the geometry has {size} \
+                 coordinates"
+            )));
+        }
+        let coord_type = coords.coord_type();
+        match ordinate {
+            Ordinate::X => coords.get_x(coord_index),
+            Ordinate::Y => coords.get_y(coord_index),
+            Ordinate::Z if coord_type.has_z() => coords.get_z(coord_index),
+            #[cfg(feature = "v3_14_0")]
+            Ordinate::M if coord_type.has_m() => coords.get_m(coord_index),
+            _ => Err(Error::GenericError(format!(
+                "the geometry doesn't have the {ordinate:?} ordinate"
+            ))),
+        }
+    }
+
     /// Returns the nth point of the given geometry.
     ///
     /// The given `Geometry` must be a `LineString`, `LinearRing` or `CircularString` otherwise it'll fail.
@@ -2109,6 +3108,76 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns the radius of the minimum bounding circle of `self`.
+    ///
+    /// The circle polygon computed by GEOS is freed right away, so prefer this method when only
+    /// the radius is needed.
+    ///
+    /// Available using the `v3_8_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT((0 0), (4 0), (0 3))")?;
+    /// assert_eq!(
+    ///     geom.bounding_circle_radius().map(|x| format!("{:.2}", x))?,
+    ///     "2.50"
+    /// );
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_8_0")]
+    fn bounding_circle_radius(&self) -> GResult<f64> {
+        minimum_bounding_circle(self).map(|(_, radius)| radius)
+    }
+
+    /// Returns the two vertices of `self` which are the farthest apart, as points.
+    ///
+    /// The farthest pair always lies on the convex hull, so only its vertices are compared. Note
+    /// that this pair isn't always a diameter of the minimum bounding circle: that circle can be
+    /// defined by three points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT((0 0), (1 1), (10 0), (2 -1))")?;
+    /// let (a, b) = geom.diameter_endpoints()?;
+    /// assert_eq!(a.distance(&b)?, 10.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn diameter_endpoints(&self) -> GResult<(Geometry, Geometry)> {
+        let mut vertices = Vec::new();
+        for_each_coord_seq(&self.convex_hull()?, &mut |coords| {
+            for line in 0..coords.size()? {
+                vertices.push((coords.get_x(line)?, coords.get_y(line)?));
+            }
+            Ok(())
+        })?;
+        let Some(&first) = vertices.first() else {
+            return Err(Error::ImpossibleOperation(
+                "cannot compute the diameter of an empty geometry".to_owned(),
+            ));
+        };
+
+        let mut best = (first, first, 0.);
+        for (i, &(ax, ay)) in vertices.iter().enumerate() {
+            for &(bx, by) in &vertices[i + 1..] {
+                let dist = (bx - ax).powi(2) + (by - ay).powi(2);
+                if dist > best.2 {
+                    best = ((ax, ay), (bx, by), dist);
+                }
+            }
+        }
+        let ((ax, ay), (bx, by), _) = best;
+        Ok((
+            Geometry::create_point(CoordSeq::new_from_vec(&[&[ax, ay]])?)?,
+            Geometry::create_point(CoordSeq::new_from_vec(&[&[bx, by]])?)?,
+        ))
+    }
+
     /// Returns a [delaunay triangulation](https://en.wikipedia.org/wiki/Delaunay_triangulation)
     /// around the vertices of `self`.
     ///
@@ -2138,20 +3207,219 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
-    fn interpolate(&self, d: f64) -> GResult<Geometry> {
-        with_context(|ctx| unsafe {
-            let ptr = nullcheck!(GEOSInterpolate_r(ctx.as_raw(), self.as_raw(), d))?;
-            Ok(Geometry::new_from_raw(ptr))
-        })
-    }
-
-    fn interpolate_normalized(&self, d: f64) -> GResult<Geometry> {
-        with_context(|ctx| unsafe {
+    /// Returns a constrained delaunay triangulation of the polygons of `self`, as a
+    /// `GeometryCollection` of triangular polygons. The edges of the input polygons are kept in
+    /// the triangulation.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 4 0, 4 4, 2 1, 0 4, 0 0))")?;
+    /// let triangles = geom.constrained_delaunay_triangulation()?;
+    ///
+    /// assert_eq!(triangles.get_num_geometries()?, 3);
+    /// assert_eq!(triangles.area()?, geom.area()?);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_10_0")]
+    fn constrained_delaunay_triangulation(&self) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSConstrainedDelaunayTriangulation_r(
+                ctx.as_raw(),
+                self.as_raw()
+            ))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    /// Splits `self` into triangles, returned as a `GeometryCollection` of polygons.
+    ///
+    /// Convex polygons (see [`is_convex`](Geom::is_convex)) are triangulated as a fan around the
+    /// first vertex of their exterior ring, which is much faster. Other polygons go through
+    /// [`constrained_delaunay_triangulation`](Geom::constrained_delaunay_triangulation).
+    ///
+    /// Whatever the orientation of the input rings, every triangle is oriented counter-clockwise.
+    /// Only X and Y are kept.
+    ///
+    /// Only works on `Polygon` and `MultiPolygon`.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))")?;
+    /// let triangles = geom.triangulate()?;
+    ///
+    /// assert_eq!(triangles.get_num_geometries()?, 2);
+    /// assert_eq!(
+    ///     triangles.get_geometry_n(0)?.to_wkt()?,
+    ///     "POLYGON ((0 0, 2 0, 2 2, 0 0))",
+    /// );
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_10_0")]
+    fn triangulate(&self) -> GResult<Geometry> {
+        let geom_type = self.geometry_type()?;
+        if !matches!(
+            geom_type,
+            GeometryTypes::Polygon | GeometryTypes::MultiPolygon
+        ) {
+            return Err(Error::ImpossibleOperation(
+                "only Polygon and MultiPolygon can be triangulated".to_owned(),
+            ));
+        }
+        let mut corners = Vec::new();
+        if geom_type == GeometryTypes::Polygon && !self.is_empty()? && self.is_convex()? {
+            let mut ring = collect_xy(&self.get_exterior_ring()?)?;
+            ring.pop();
+            for i in 2..ring.len() {
+                corners.push([ring[0], ring[i - 1], ring[i]]);
+            }
+        } else {
+            let triangulation = self.constrained_delaunay_triangulation()?;
+            for n in 0..triangulation.get_num_geometries()? {
+                let ring = collect_xy(&triangulation.get_geometry_n(n)?)?;
+                corners.push([ring[0], ring[1], ring[2]]);
+            }
+        }
+        let mut triangles = Vec::with_capacity(corners.len());
+        for [a, b, c] in corners {
+            // Twice the signed area, positive when counter-clockwise.
+            let cross = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+            if cross == 0. {
+                // Collinear vertices of the fan.
+                continue;
+            }
+            let (b, c) = if cross > 0. { (b, c) } else { (c, b) };
+            let ring = CoordSeq::new_from_vec(&[[a.0, a.1], [b.0, b.1], [c.0, c.1], [a.0, a.1]])?;
+            triangles.push(Geometry::create_polygon(
+                Geometry::create_linear_ring(ring)?,
+                vec![],
+            )?);
+        }
+        Geometry::create_geometry_collection(triangles)
+    }
+
+    fn interpolate(&self, d: f64) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSInterpolate_r(ctx.as_raw(), self.as_raw(), d))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    fn interpolate_normalized(&self, d: f64) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
             let ptr = nullcheck!(GEOSInterpolateNormalized_r(ctx.as_raw(), self.as_raw(), d))?;
             Ok(Geometry::new_from_raw(ptr))
         })
     }
 
+    /// Same as [`interpolate`](Geom::interpolate), but also computes the Z of the returned point
+    /// by linear interpolation between the Z values of the vertices around it.
+    ///
+    /// `self` must be a non-empty `LineString` or `LinearRing` with a Z coordinate, otherwise it'll
+    /// fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING Z(0 0 0, 10 0 10, 10 10 0)")?;
+    ///
+    /// assert_eq!(line.interpolate_3d(5.)?.to_wkt()?, "POINT Z (5 0 5)");
+    /// assert_eq!(line.interpolate_3d(12.)?.to_wkt()?, "POINT Z (10 2 8)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn interpolate_3d(&self, distance: f64) -> GResult<Geometry> {
+        if !matches!(
+            self.geometry_type()?,
+            GeometryTypes::LineString | GeometryTypes::LinearRing
+        ) || self.is_empty()?
+            || !self.has_z()?
+        {
+            return Err(Error::ImpossibleOperation(
+                "3D interpolation is only possible on non-empty lines with a Z coordinate"
+                    .to_owned(),
+            ));
+        }
+        let point = self.interpolate(distance)?;
+        let coords = self.get_coord_seq()?;
+        let length = self.length()?;
+        // Negative distances are measured from the end, like `GEOSInterpolate` does.
+        let distance = if distance < 0. {
+            distance + length
+        } else {
+            distance
+        }
+        .clamp(0., length);
+
+        let mut z = coords.get_z(0)?;
+        let mut start = 0.;
+        for line in 1..coords.size()? {
+            let (x0, y0) = (coords.get_x(line - 1)?, coords.get_y(line - 1)?);
+            let (x1, y1) = (coords.get_x(line)?, coords.get_y(line)?);
+            let segment_length = (x1 - x0).hypot(y1 - y0);
+            if start + segment_length >= distance && segment_length > 0. {
+                let fraction = (distance - start) / segment_length;
+                let (z0, z1) = (coords.get_z(line - 1)?, coords.get_z(line)?);
+                z = z0 + (z1 - z0) * fraction;
+                break;
+            }
+            start += segment_length;
+        }
+        Geometry::create_point(CoordSeq::new_from_vec(&[&[
+            point.get_x()?,
+            point.get_y()?,
+            z,
+        ]])?)
+    }
+
+    /// Returns points placed every `spacing` along `self`, each paired with its distance along
+    /// `self` (its measure).
+    ///
+    /// The first point is always the start of `self` (measure `0`). The end of `self` is only
+    /// included when the length of `self` is a multiple of `spacing`, up to a relative tolerance of
+    /// `1e-9` to absorb rounding errors (a length of `0.3` is a multiple of `0.1`). `spacing` must
+    /// be strictly positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0)")?;
+    /// let markers = line.interpolate_equidistant(4.)?;
+    ///
+    /// assert_eq!(markers.len(), 3);
+    /// assert_eq!(markers[2].0.to_wkt()?, "POINT (8 0)");
+    /// assert_eq!(markers[2].1, 8.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn interpolate_equidistant(&self, spacing: f64) -> GResult<Vec<(Geometry, f64)>> {
+        if !spacing.is_finite() || spacing <= 0. {
+            return Err(Error::GenericError(
+                "spacing must be a strictly positive number".to_owned(),
+            ));
+        }
+        let length = self.length()?;
+        let count = (length / spacing * (1. + 1e-9)).floor() as usize;
+        (0..=count)
+            .map(|n| {
+                // With the tolerance, the last measure can be slightly past the end.
+                let measure = (n as f64 * spacing).min(length);
+                Ok((self.interpolate(measure)?, measure))
+            })
+            .collect()
+    }
+
     fn project<G: Geom>(&self, p: &G) -> GResult<f64> {
         with_context(|ctx| unsafe {
             errcheck!(-1.0, GEOSProject_r(ctx.as_raw(), self.as_raw(), p.as_raw()))
@@ -2167,6 +3435,317 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Projects `point` on `self` and returns, in the tuple elements order:
+    ///
+    /// 1. The distance along `self` of the projected point (same as [`project`](Geom::project)).
+    /// 2. The distance between `point` and its projection on `self`.
+    ///
+    /// `self` must be a `LineString`, `LinearRing` or `MultiLineString`, otherwise it'll fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0)")?;
+    /// let point = Geometry::new_from_wkt("POINT(5 2)")?;
+    ///
+    /// assert_eq!(line.project_with_offset(&point)?, (5., 2.));
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn project_with_offset<G: Geom>(&self, point: &G) -> GResult<(f64, f64)> {
+        if !matches!(
+            self.geometry_type()?,
+            GeometryTypes::LineString | GeometryTypes::LinearRing | GeometryTypes::MultiLineString
+        ) {
+            return Err(Error::ImpossibleOperation(
+                "projection is only possible on linear geometries".to_owned(),
+            ));
+        }
+        let measure = self.project(point)?;
+        let offset = self.interpolate(measure)?.distance(point)?;
+        Ok((measure, offset))
+    }
+
+    /// Returns the segment of `self` closest to `point`, as a `LineString` made of its two
+    /// vertices.
+    ///
+    /// `point` is [projected](Geom::project) on `self` and the segment containing the projection
+    /// is returned. When the projection falls on a vertex, the segment ending there wins.
+    ///
+    /// `self` must be a `LineString`, `LinearRing` or `MultiLineString` with at least one
+    /// segment, otherwise it'll fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 10 10)")?;
+    /// let point = Geometry::new_from_wkt("POINT(12 6)")?;
+    ///
+    /// assert_eq!(line.nearest_segment(&point)?.to_wkt()?, "LINESTRING (10 0, 10 10)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn nearest_segment<G: Geom>(&self, point: &G) -> GResult<Geometry> {
+        if !matches!(
+            self.geometry_type()?,
+            GeometryTypes::LineString | GeometryTypes::LinearRing | GeometryTypes::MultiLineString
+        ) {
+            return Err(Error::ImpossibleOperation(
+                "nearest segment is only available on linear geometries".to_owned(),
+            ));
+        }
+        let measure = self.project(point)?;
+        let mut start = 0.;
+        let mut nearest = None;
+        let mut last = None;
+        for_each_coord_seq(self, &mut |coords| {
+            for line in 1..coords.size()? {
+                let a = (coords.get_x(line - 1)?, coords.get_y(line - 1)?);
+                let b = (coords.get_x(line)?, coords.get_y(line)?);
+                let end = start + (b.0 - a.0).hypot(b.1 - a.1);
+                if nearest.is_none() && measure <= end {
+                    nearest = Some((a, b));
+                }
+                last = Some((a, b));
+                start = end;
+            }
+            Ok(())
+        })?;
+        // The summed lengths can end up slightly below the measure computed by GEOS.
+        let Some(((ax, ay), (bx, by))) = nearest.or(last) else {
+            return Err(Error::ImpossibleOperation(
+                "the geometry doesn't have any segment".to_owned(),
+            ));
+        };
+        Geometry::create_line_string(CoordSeq::new_from_vec(&[[ax, ay], [bx, by]])?)
+    }
+
+    /// Returns a `MultiPoint` made of the midpoint of every segment of `self`.
+    ///
+    /// `self` must be a `LineString`, `LinearRing` or `MultiLineString`, otherwise it'll fail. The
+    /// midpoints of the parts of a `MultiLineString` are concatenated in order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 2 0, 2 2)")?;
+    /// let midpoints = line.segment_midpoints()?;
+    ///
+    /// assert_eq!(midpoints.get_num_geometries()?, 2);
+    /// assert_eq!(midpoints.get_geometry_n(0)?.to_wkt()?, "POINT (1 0)");
+    /// assert_eq!(midpoints.get_geometry_n(1)?.to_wkt()?, "POINT (2 1)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn segment_midpoints(&self) -> GResult<Geometry> {
+        if !matches!(
+            self.geometry_type()?,
+            GeometryTypes::LineString | GeometryTypes::LinearRing | GeometryTypes::MultiLineString
+        ) {
+            return Err(Error::ImpossibleOperation(
+                "segment midpoints are only available on linear geometries".to_owned(),
+            ));
+        }
+        let mut midpoints = Vec::new();
+        for_each_coord_seq(self, &mut |coords| {
+            for line in 1..coords.size()? {
+                let x = (coords.get_x(line - 1)? + coords.get_x(line)?) / 2.;
+                let y = (coords.get_y(line - 1)? + coords.get_y(line)?) / 2.;
+                midpoints.push(Geometry::create_point(CoordSeq::new_from_vec(&[&[x, y]])?)?);
+            }
+            Ok(())
+        })?;
+        Geometry::create_multipoint(midpoints)
+    }
+
+    /// Returns the turn angle, in radians, at each vertex of `self`: the angle between the
+    /// direction of the segment coming to the vertex and the direction of the segment leaving it.
+    ///
+    /// Angles are in `[-π, π]`: positive for a left (counter-clockwise) turn, negative for a
+    /// right (clockwise) one and `0` when going straight. The endpoints of an open line don't
+    /// have an angle and are omitted. For a closed line or a ring, every vertex has an angle,
+    /// starting with the first one, and the closing vertex isn't repeated.
+    ///
+    /// `self` must be a `LineString` or a `LinearRing`, otherwise it'll fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 1 0, 2 0, 2 -1)")?;
+    /// let angles = line.vertex_angles()?;
+    ///
+    /// assert_eq!(angles, vec![0., -std::f64::consts::FRAC_PI_2]);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn vertex_angles(&self) -> GResult<Vec<f64>> {
+        if !matches!(
+            self.geometry_type()?,
+            GeometryTypes::LineString | GeometryTypes::LinearRing
+        ) {
+            return Err(Error::ImpossibleOperation(
+                "vertex angles are only available on LineString and LinearRing".to_owned(),
+            ));
+        }
+        let mut vertices = collect_xy(self)?;
+        let closed = vertices.len() > 3 && vertices.first() == vertices.last();
+        if closed {
+            // The closing vertex is seen again as the middle of the last and first segments.
+            vertices.pop();
+            let (first, last) = (vertices[0], vertices[vertices.len() - 1]);
+            vertices.insert(0, last);
+            vertices.push(first);
+        }
+        Ok(vertices
+            .windows(3)
+            .map(|w| {
+                let (ix, iy) = (w[1].0 - w[0].0, w[1].1 - w[0].1);
+                let (ox, oy) = (w[2].0 - w[1].0, w[2].1 - w[1].1);
+                (ix * oy - iy * ox).atan2(ix * ox + iy * oy)
+            })
+            .collect())
+    }
+
+    /// Splits `self` into consecutive lines of at most `max_vertices` vertices, returned as a
+    /// `MultiLineString`. The vertex where a line is split is shared by the two adjacent chunks,
+    /// so they stay connected.
+    ///
+    /// `self` must be a `LineString` and `max_vertices` at least `2`, otherwise it'll fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(0 0, 1 0, 2 0, 3 0, 4 0, 5 0)")?;
+    /// let chunks = line.chunk_line(3)?;
+    ///
+    /// assert_eq!(
+    ///     chunks.to_wkt()?,
+    ///     "MULTILINESTRING ((0 0, 1 0, 2 0), (2 0, 3 0, 4 0), (4 0, 5 0))",
+    /// );
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn chunk_line(&self, max_vertices: usize) -> GResult<Geometry> {
+        if self.geometry_type()? != GeometryTypes::LineString {
+            return Err(Error::ImpossibleOperation(
+                "only LineString can be chunked".to_owned(),
+            ));
+        }
+        if max_vertices < 2 {
+            return Err(Error::GenericError(
+                "max_vertices must be at least 2".to_owned(),
+            ));
+        }
+        let coords = self.get_coord_seq()?;
+        let coord_type = coords.coord_type();
+        let size = coords.size()?;
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        while start + 1 < size {
+            let end = (start + max_vertices - 1).min(size - 1);
+            let mut chunk = CoordSeq::new((end - start + 1) as _, coord_type)?;
+            for line in start..=end {
+                chunk.set_x(line - start, coords.get_x(line)?)?;
+                chunk.set_y(line - start, coords.get_y(line)?)?;
+                if coord_type.has_z() {
+                    chunk.set_z(line - start, coords.get_z(line)?)?;
+                }
+                #[cfg(feature = "v3_14_0")]
+                if coord_type.has_m() {
+                    chunk.set_m(line - start, coords.get_m(line)?)?;
+                }
+            }
+            chunks.push(Geometry::create_line_string(chunk)?);
+            start = end;
+        }
+        Geometry::create_multiline_string(chunks)
+    }
+
+    /// Splits the lines of `self` where they cross the antimeridian and returns the parts as a
+    /// `MultiLineString`.
+    ///
+    /// The coordinates are assumed to be WGS84 longitudes and latitudes, in degrees. A segment
+    /// whose longitude jumps by more than 180° is considered to take the short way across the
+    /// antimeridian: it's cut at ±180°, the latitude of the cut being interpolated linearly. The
+    /// part before the cut ends on one side (`180` or `-180`), the next one starts on the other.
+    /// When a vertex already is on the antimeridian, like in lines which were split before, no
+    /// vertex is added there: a segment from `180` to `-180` just separates two parts.
+    ///
+    /// `self` must be a `LineString` or a `MultiLineString`, otherwise it'll fail. Only X and Y
+    /// are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let line = Geometry::new_from_wkt("LINESTRING(170 0, -170 10)")?;
+    /// let split = line.split_antimeridian()?;
+    ///
+    /// assert_eq!(
+    ///     split.to_wkt()?,
+    ///     "MULTILINESTRING ((170 0, 180 5), (-180 5, -170 10))",
+    /// );
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn split_antimeridian(&self) -> GResult<Geometry> {
+        if !matches!(
+            self.geometry_type()?,
+            GeometryTypes::LineString | GeometryTypes::MultiLineString
+        ) {
+            return Err(Error::ImpossibleOperation(
+                "only LineString and MultiLineString can be split at the antimeridian".to_owned(),
+            ));
+        }
+        let mut parts = Vec::new();
+        for_each_coord_seq(self, &mut |coords| {
+            let size = coords.size()?;
+            if size == 0 {
+                return Ok(());
+            }
+            let mut part = vec![[coords.get_x(0)?, coords.get_y(0)?]];
+            for line in 1..size {
+                let [x0, y0] = part[part.len() - 1];
+                let (x1, y1) = (coords.get_x(line)?, coords.get_y(line)?);
+                if (x1 - x0).abs() > 180. {
+                    // Going east, the line leaves at 180 and comes back at -180.
+                    let side = if x0 > x1 { 180. } else { -180. };
+                    let mut y = y0;
+                    if x0 != side {
+                        let unwrapped_x1 = x1 + 2. * side;
+                        y += (side - x0) / (unwrapped_x1 - x0) * (y1 - y0);
+                        part.push([side, y]);
+                    }
+                    let next = if x1 == -side {
+                        vec![]
+                    } else {
+                        vec![[-side, y]]
+                    };
+                    let done = std::mem::replace(&mut part, next);
+                    // A line starting on the antimeridian leaves a single vertex behind.
+                    if done.len() > 1 {
+                        parts.push(Geometry::create_line_string(CoordSeq::new_from_vec(
+                            &done,
+                        )?)?);
+                    }
+                }
+                part.push([x1, y1]);
+            }
+            if part.len() > 1 {
+                parts.push(Geometry::create_line_string(CoordSeq::new_from_vec(
+                    &part,
+                )?)?);
+            }
+            Ok(())
+        })?;
+        Geometry::create_multiline_string(parts)
+    }
+
     fn node(&self) -> GResult<Geometry> {
         with_context(|ctx| unsafe {
             let ptr = nullcheck!(GEOSNode_r(ctx.as_raw(), self.as_raw()))?;
@@ -2174,6 +3753,82 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns the number of points where the linework of `self` (its boundary for polygons)
+    /// crosses or touches itself outside of its vertices. These are the nodes added by
+    /// [`node`](Geom::node). `0` means no such intersection was found.
+    ///
+    /// Intersections located on an existing vertex, like a ring touching itself at one of its
+    /// vertices, aren't counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 2, 2 0, 0 2, 0 0))")?;
+    /// assert_eq!(geom.self_intersection_count()?, 1);
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))")?;
+    /// assert_eq!(geom.self_intersection_count()?, 0);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn self_intersection_count(&self) -> GResult<usize> {
+        let linework = match self.get_dimension()? {
+            DimensionType::Surface => self.boundary()?,
+            DimensionType::Curve => Geom::clone(self)?,
+            DimensionType::Point => {
+                return Err(Error::ImpossibleOperation(
+                    "points don't have self-intersections".to_owned(),
+                ))
+            }
+        };
+        Ok(added_nodes(&linework)?.len())
+    }
+
+    /// Returns a `MultiPoint` of the points where a ring of `self` crosses or touches itself
+    /// outside of its vertices.
+    ///
+    /// The exterior ring and every interior ring are [noded](Geom::node) independently, so
+    /// intersections between two different rings aren't reported. A point shared by several
+    /// rings is only returned once.
+    ///
+    /// Only works on `Polygon` and `MultiPolygon`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 2, 2 0, 0 2, 0 0))")?;
+    /// let points = geom.ring_self_intersection_points()?;
+    ///
+    /// assert_eq!(points.get_num_geometries()?, 1);
+    /// assert_eq!(points.get_geometry_n(0)?.to_wkt()?, "POINT (1 1)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn ring_self_intersection_points(&self) -> GResult<Geometry> {
+        if !matches!(
+            self.geometry_type()?,
+            GeometryTypes::Polygon | GeometryTypes::MultiPolygon
+        ) {
+            return Err(Error::ImpossibleOperation(
+                "only Polygon and MultiPolygon have rings".to_owned(),
+            ));
+        }
+        let mut nodes = Vec::new();
+        for_each_coord_seq(self, &mut |coords| {
+            nodes.extend(added_nodes(&Geometry::create_line_string(coords)?)?);
+            Ok(())
+        })?;
+        nodes.sort_by(cmp_xy);
+        nodes.dedup();
+        let points = nodes
+            .into_iter()
+            .map(|(x, y)| Geometry::create_point(CoordSeq::new_from_vec(&[&[x, y]])?))
+            .collect::<GResult<Vec<_>>>()?;
+        Geometry::create_multipoint(points)
+    }
+
     ///  Return an offset line at a given distance and side from an input line. All points of the
     /// returned geometries are not further than the given distance from the input geometry.
     ///
@@ -2215,6 +3870,35 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns the `(left, right)` offset lines of `self`, computed by calling
+    /// [`offset_curve`](Geom::offset_curve) with `distance` and `-distance`.
+    ///
+    /// See [`offset_curve`](Geom::offset_curve) for the meaning of the other parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, JoinStyle};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 10 0)")?;
+    /// let (left, right) = geom.offset_both(1., 8, JoinStyle::Round, 5.)?;
+    /// assert_eq!(left.get_coord_seq()?.get_y(0)?, 1.);
+    /// assert_eq!(right.get_coord_seq()?.get_y(0)?, -1.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn offset_both(
+        &self,
+        distance: f64,
+        quad_segs: i32,
+        join: JoinStyle,
+        mitre_limit: f64,
+    ) -> GResult<(Geometry, Geometry)> {
+        Ok((
+            self.offset_curve(distance, quad_segs, join, mitre_limit)?,
+            self.offset_curve(-distance, quad_segs, join, mitre_limit)?,
+        ))
+    }
+
     fn point_on_surface(&self) -> GResult<Geometry> {
         with_context(|ctx| unsafe {
             let ptr = nullcheck!(GEOSPointOnSurface_r(ctx.as_raw(), self.as_raw()))?;
@@ -2334,6 +4018,110 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         GeoJSONWriter::new()?.write_formatted(self, indent)
     }
 
+    /// Converts a [`Geometry`] to a `GeoJSON` [`serde_json::Value`], using the
+    /// [`GeoJSONWriter`](crate::GeoJSONWriter) type with the given `indent`.
+    ///
+    /// Available using the `v3_10_0` and `serde_json` features.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)")?;
+    /// let value = point_geom.to_geojson_value(-1)?;
+    ///
+    /// assert_eq!(value["type"], "Point");
+    /// assert_eq!(value["coordinates"], serde_json::json!([2.5, 2.5]));
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(all(feature = "v3_10_0", feature = "serde_json"))]
+    fn to_geojson_value(&self, indent: i32) -> GResult<serde_json::Value> {
+        let geojson = GeoJSONWriter::new()?.write_formatted(self, indent)?;
+        serde_json::from_str(&geojson)
+            .map_err(|e| Error::GenericError(format!("GeoJSON parsing failed: {e}")))
+    }
+
+    /// Returns an SVG path (the content of the `d` attribute of a `<path>` element) drawing
+    /// `self`, with coordinates rounded to `decimals` decimals.
+    ///
+    /// Every line, ring and point starts a new sub-path with `M`, followed by `L` for the next
+    /// vertices. Rings, including holes, are closed with `Z` instead of repeating their first
+    /// vertex. Coordinates are written as they are: since the Y axis of SVG grows downward, the
+    /// drawing is upside down unless flipped by the caller, for example with a
+    /// `transform="scale(1, -1)"` attribute. Holes are drawn with the `fill-rule="evenodd"`
+    /// attribute.
+    ///
+    /// Curved geometries aren't supported. An empty geometry gives an empty path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTILINESTRING((0 0, 1.26 1), (2 0, 3 0))")?;
+    ///
+    /// assert_eq!(geom.to_svg_path(1)?, "M 0.0 0.0 L 1.3 1.0 M 2.0 0.0 L 3.0 0.0");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn to_svg_path(&self, decimals: u32) -> GResult<String> {
+        fn write_coords(
+            coords: &CoordSeq,
+            closed: bool,
+            decimals: usize,
+            path: &mut Vec<String>,
+        ) -> GResult<()> {
+            let mut size = coords.size()?;
+            if closed && size > 1 {
+                // `Z` goes back to the first vertex.
+                size -= 1;
+            }
+            for line in 0..size {
+                let command = if line == 0 { "M" } else { "L" };
+                let (x, y) = (coords.get_x(line)?, coords.get_y(line)?);
+                path.push(format!("{command} {x:.decimals$} {y:.decimals$}"));
+            }
+            if closed && size > 0 {
+                path.push("Z".to_owned());
+            }
+            Ok(())
+        }
+
+        fn write<G: Geom>(geom: &G, decimals: usize, path: &mut Vec<String>) -> GResult<()> {
+            match geom.geometry_type()? {
+                GeometryTypes::Point | GeometryTypes::LineString => {
+                    write_coords(&geom.get_coord_seq()?, false, decimals, path)
+                }
+                GeometryTypes::LinearRing => {
+                    write_coords(&geom.get_coord_seq()?, true, decimals, path)
+                }
+                GeometryTypes::Polygon => {
+                    if geom.is_empty()? {
+                        return Ok(());
+                    }
+                    write(&geom.get_exterior_ring()?, decimals, path)?;
+                    for n in 0..geom.get_num_interior_rings()? {
+                        write(&geom.get_interior_ring_n(n)?, decimals, path)?;
+                    }
+                    Ok(())
+                }
+                t if t.is_collection() => {
+                    for n in 0..geom.get_num_geometries()? {
+                        write(&geom.get_geometry_n(n)?, decimals, path)?;
+                    }
+                    Ok(())
+                }
+                t => Err(Error::ImpossibleOperation(format!(
+                    "cannot write a {t:?} as an SVG path"
+                ))),
+            }
+        }
+
+        let mut path = Vec::new();
+        write(self, decimals as usize, &mut path)?;
+        Ok(path.join(" "))
+    }
+
     /// Creates a new [`PreparedGeometry`] from the current `Geometry`.
     ///
     /// # Example
@@ -2474,8 +4262,140 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
             } else if let Some(err) = trampoline.err {
                 Err(err)
             } else {
-                Err(Error::GeosError(("GEOSGeom_transformXY_r", ctx.get_last_error())).into())
+                Err(Error::GeosError(("GEOSGeom_transformXY_r", ctx.get_last_error())).into())
+            }
+        })
+    }
+
+    /// Apply a batch transform to all coordinates in a copy of input geometry: the X and Y of all
+    /// the vertices are given to `f` as two slices, to be modified in place. The transformed
+    /// geometry keeps the structure of `self`.
+    ///
+    /// The slices have one entry per vertex and can't be resized. Z and M values, if present,
+    /// are not modified by this function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(1.5 2.5, 3 4)")?;
+    /// let transformed = geom.transform_coords_bulk(|xs, ys| {
+    ///     xs.iter_mut().for_each(|x| *x *= 2.);
+    ///     ys.iter_mut().for_each(|y| *y -= 1.);
+    /// })?;
+    /// assert_eq!(transformed.to_wkt()?, "LINESTRING (3 1.5, 6 3)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn transform_coords_bulk<F: FnOnce(&mut [f64], &mut [f64])>(&self, f: F) -> GResult<Geometry> {
+        let (mut xs, mut ys): (Vec<f64>, Vec<f64>) = collect_xy(self)?.into_iter().unzip();
+        f(&mut xs, &mut ys);
+
+        // `collect_xy` and `map_coord_seqs` visit the vertices in the same order.
+        let mut index = 0;
+        map_coord_seqs(self, &mut |mut coords| {
+            for line in 0..coords.size()? {
+                coords.set_x(line, xs[index])?;
+                coords.set_y(line, ys[index])?;
+                index += 1;
+            }
+            Ok(coords)
+        })
+    }
+
+    /// Returns a copy of `self` where every vertex gets the Z of the nearest vertex of
+    /// `reference`, typically to restore the Z lost by a 2D operation.
+    ///
+    /// Only the vertices of `reference` are looked at, in 2D: Z isn't interpolated along its
+    /// segments, so vertices of `self` which aren't close to a reference vertex get the Z of a
+    /// possibly distant one. On ties, the first reference vertex wins. The existing Z of `self`,
+    /// if any, is replaced.
+    ///
+    /// `reference` must have Z coordinates and at least one vertex, otherwise it'll fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 10 0)")?;
+    /// let reference = Geometry::new_from_wkt("MULTIPOINT Z((0 0 5), (9 1 7))")?;
+    ///
+    /// let coords = geom.restore_z_from(&reference)?.get_coord_seq()?;
+    /// assert_eq!(coords.get_z(0)?, 5.);
+    /// assert_eq!(coords.get_z(1)?, 7.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn restore_z_from<G: Geom>(&self, reference: &G) -> GResult<Geometry> {
+        let mut reference_vertices = Vec::new();
+        for_each_coord_seq(reference, &mut |coords| {
+            if !coords.coord_type().has_z() {
+                return Err(Error::ImpossibleOperation(
+                    "the reference geometry doesn't have Z coordinates".to_owned(),
+                ));
+            }
+            for line in 0..coords.size()? {
+                let (x, y) = (coords.get_x(line)?, coords.get_y(line)?);
+                let index = reference_vertices.len();
+                reference_vertices.push((x, y, coords.get_z(line)?, index));
+            }
+            Ok(())
+        })?;
+        if reference_vertices.is_empty() {
+            return Err(Error::ImpossibleOperation(
+                "the reference geometry doesn't have any vertex".to_owned(),
+            ));
+        }
+        // Sorted by X, the search around a vertex can stop as soon as the X distance alone is
+        // larger than the nearest distance found.
+        reference_vertices.sort_by(|a, b| a.0.total_cmp(&b.0));
+        map_coord_seqs(self, &mut |coords| {
+            #[cfg(feature = "v3_14_0")]
+            let with_m = coords.coord_type().has_m();
+            #[cfg(feature = "v3_14_0")]
+            let coord_type = if with_m {
+                CoordType::XYZM
+            } else {
+                CoordType::XYZ
+            };
+            #[cfg(not(feature = "v3_14_0"))]
+            let coord_type = CoordType::XYZ;
+            let size = coords.size()?;
+            let mut restored = CoordSeq::new(size as _, coord_type)?;
+            for line in 0..size {
+                let (x, y) = (coords.get_x(line)?, coords.get_y(line)?);
+                let mut nearest = (f64::INFINITY, usize::MAX, 0.);
+                let mut visit = |&(rx, ry, rz, index): &(f64, f64, f64, usize)| {
+                    let dist_x = (rx - x).powi(2);
+                    if dist_x > nearest.0 {
+                        return false;
+                    }
+                    let dist = dist_x + (ry - y).powi(2);
+                    if dist < nearest.0 || (dist == nearest.0 && index < nearest.1) {
+                        nearest = (dist, index, rz);
+                    }
+                    true
+                };
+                let start = reference_vertices.partition_point(|vertex| vertex.0 < x);
+                for vertex in &reference_vertices[start..] {
+                    if !visit(vertex) {
+                        break;
+                    }
+                }
+                for vertex in reference_vertices[..start].iter().rev() {
+                    if !visit(vertex) {
+                        break;
+                    }
+                }
+                restored.set_x(line, x)?;
+                restored.set_y(line, y)?;
+                restored.set_z(line, nearest.2)?;
+                #[cfg(feature = "v3_14_0")]
+                if with_m {
+                    restored.set_m(line, coords.get_m(line)?)?;
+                }
             }
+            Ok(restored)
         })
     }
 
@@ -2493,6 +4413,52 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns the intersection of `self` and a circle of center (`cx`, `cy`) and radius
+    /// `radius`. The circle is approximated by [buffering](Geom::buffer) its center with
+    /// `quad_segs` segments per quadrant, so it's slightly smaller than the true circle.
+    ///
+    /// `radius` must be strictly positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((-5 -5, 5 -5, 5 5, -5 5, -5 -5))")?;
+    /// let clipped = geom.clip_by_circle(0., 0., 1., 32)?;
+    ///
+    /// assert_eq!(clipped.area().map(|x| format!("{:.2}", x))?, "3.14");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn clip_by_circle(&self, cx: f64, cy: f64, radius: f64, quad_segs: i32) -> GResult<Geometry> {
+        if !radius.is_finite() || radius <= 0. {
+            return Err(Error::GenericError(
+                "radius must be a strictly positive number".to_owned(),
+            ));
+        }
+        let center = Geometry::create_point(CoordSeq::new_from_vec(&[&[cx, cy]])?)?;
+        self.intersection(&center.buffer(radius, quad_segs)?)
+    }
+
+    /// Returns a copy of `self` where vertices are inserted so that no segment is longer than
+    /// `tolerance`. The original vertices are all kept, unchanged.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 0 8, 4 8)")?;
+    /// let densified = geom.densify(2.)?;
+    ///
+    /// assert_eq!(
+    ///     densified.to_wkt()?,
+    ///     "LINESTRING (0 0, 0 2, 0 4, 0 6, 0 8, 2 8, 4 8)"
+    /// );
+    /// # Ok::<(), geos::Error>(())
+    /// ```
     #[cfg(feature = "v3_10_0")]
     fn densify(&self, tolerance: f64) -> GResult<Geometry> {
         with_context(|ctx| unsafe {
@@ -2501,6 +4467,176 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Same as [`densify`](Geom::densify), but fails instead of densifying if the result could
+    /// have more than `max_output_vertices` vertices.
+    ///
+    /// The number of output vertices is estimated from the length of `self` before densifying.
+    /// The estimate is conservative: it's never below the actual count, but can be above it by
+    /// up to twice the number of vertices of `self`, so a result close to the cap can be rejected.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 1000 0)")?;
+    ///
+    /// assert!(geom.densify_capped(0.001, 10_000).is_err());
+    /// assert_eq!(geom.densify_capped(1., 10_000)?.get_num_points()?, 1001);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_10_0")]
+    fn densify_capped(&self, tolerance: f64, max_output_vertices: usize) -> GResult<Geometry> {
+        if !tolerance.is_finite() || tolerance <= 0. {
+            return Err(Error::GenericError(
+                "tolerance must be a strictly positive number".to_owned(),
+            ));
+        }
+        // Each segment gets at most `length / tolerance + 1` new vertices.
+        let vertices = self.get_num_coordinates()? as f64;
+        let estimate = 2. * vertices + (self.length()? / tolerance).ceil();
+        if estimate > max_output_vertices as f64 {
+            return Err(Error::GenericError(format!(
+                "densifying could produce up to {estimate} vertices, more than the maximum of \
+                 {max_output_vertices}"
+            )));
+        }
+        self.densify(tolerance)
+    }
+
+    /// Returns a copy of `self` ready to be reprojected: it's [densified](Geom::densify) so that
+    /// no segment is longer than `max_segment_length`, then [made valid](Geom::make_valid).
+    ///
+    /// Straight segments usually become curves once reprojected, so long segments have to be
+    /// split beforehand to follow them. Inserting vertices can however create (or reveal) an
+    /// invalid geometry which would break later operations on the transformed one. A typical
+    /// workflow is:
+    ///
+    /// 1. call `prepare_for_reprojection` with a length small compared to the distortion of the
+    ///    target projection,
+    /// 2. transform the result, for example with [`transform_xy`](Geom::transform_xy),
+    /// 3. simplify it again if needed.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 8 0, 8 8, 0 8, 0 0))")?;
+    /// let prepared = geom.prepare_for_reprojection(4.)?;
+    ///
+    /// assert_eq!(prepared.get_num_coordinates()?, 9);
+    /// assert!(prepared.is_valid()?);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_10_0")]
+    fn prepare_for_reprojection(&self, max_segment_length: f64) -> GResult<Geometry> {
+        if !max_segment_length.is_finite() || max_segment_length <= 0. {
+            return Err(Error::GenericError(
+                "max_segment_length must be a strictly positive number".to_owned(),
+            ));
+        }
+        self.densify(max_segment_length)?.make_valid()
+    }
+
+    /// Returns a copy of `self` where vertices are inserted wherever a segment crosses a line of
+    /// the grid of origin `grid_origin` and cell size `spacing`. The original vertices are all
+    /// kept, unchanged.
+    ///
+    /// Unlike [`densify`](Geom::densify), which divides each segment evenly, the new vertices
+    /// only depend on the grid. Two geometries sharing an edge and densified with the same grid
+    /// get exactly the same vertices on it, whatever the direction of the edge, so they stay
+    /// noded together.
+    ///
+    /// Z is interpolated along the segments. M isn't kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0.5 0, 3.5 0)")?;
+    /// let densified = geom.densify_aligned((0., 0.), 1.)?;
+    ///
+    /// assert_eq!(densified.to_wkt()?, "LINESTRING (0.5 0, 1 0, 2 0, 3 0, 3.5 0)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn densify_aligned(&self, grid_origin: (f64, f64), spacing: f64) -> GResult<Geometry> {
+        if !spacing.is_finite() || spacing <= 0. {
+            return Err(Error::GenericError(
+                "spacing must be a strictly positive number".to_owned(),
+            ));
+        }
+        let origin = [grid_origin.0, grid_origin.1];
+        map_coord_seqs(self, &mut |coords| {
+            let size = coords.size()?;
+            if size < 2 {
+                return Ok(coords);
+            }
+            let has_z = coords.coord_type().has_z();
+            let mut vertices = Vec::with_capacity(size);
+            for line in 0..size {
+                let mut vertex = vec![coords.get_x(line)?, coords.get_y(line)?];
+                if has_z {
+                    vertex.push(coords.get_z(line)?);
+                }
+                vertices.push(vertex);
+            }
+
+            let mut densified = vec![vertices[0].clone()];
+            for segment in vertices.windows(2) {
+                // The crossings are computed from the smallest endpoint, so that they don't
+                // depend on the direction of the segment.
+                let reversed = cmp_xy(
+                    &(segment[0][0], segment[0][1]),
+                    &(segment[1][0], segment[1][1]),
+                ) == std::cmp::Ordering::Greater;
+                let (p, q) = if reversed {
+                    (&segment[1], &segment[0])
+                } else {
+                    (&segment[0], &segment[1])
+                };
+                let mut crossings = Vec::new();
+                for axis in 0..2 {
+                    let (low, high) = (p[axis].min(q[axis]), p[axis].max(q[axis]));
+                    let first = ((low - origin[axis]) / spacing).ceil() as i64;
+                    let last = ((high - origin[axis]) / spacing).floor() as i64;
+                    for k in first..=last {
+                        let grid_line = origin[axis] + k as f64 * spacing;
+                        let t = (grid_line - p[axis]) / (q[axis] - p[axis]);
+                        if t > 0. && t < 1. {
+                            crossings.push((t, axis, grid_line));
+                        }
+                    }
+                }
+                crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+                let mut points: Vec<(f64, Vec<f64>)> = Vec::new();
+                for (t, axis, grid_line) in crossings {
+                    match points.last_mut() {
+                        // A segment going through a grid node crosses two lines at once.
+                        Some((last_t, point)) if t - *last_t <= 1e-12 => point[axis] = grid_line,
+                        _ => {
+                            let mut point: Vec<f64> =
+                                p.iter().zip(q).map(|(a, b)| a + t * (b - a)).collect();
+                            point[axis] = grid_line;
+                            points.push((t, point));
+                        }
+                    }
+                }
+                if reversed {
+                    points.reverse();
+                }
+                densified.extend(points.into_iter().map(|(_, point)| point));
+                densified.push(segment[1].clone());
+            }
+            CoordSeq::new_from_vec(&densified)
+        })
+    }
+
     #[cfg(feature = "v3_11_0")]
     fn remove_repeated_points(&self, tolerance: f64) -> GResult<Geometry> {
         with_context(|ctx| unsafe {
@@ -2545,6 +4681,55 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Splits the envelope of `self` into a regular grid of `nx` columns by `ny` rows, returned as
+    /// a `GeometryCollection` of rectangular polygons ordered row by row, starting from the
+    /// bottom-left cell.
+    ///
+    /// Both `nx` and `ny` must be at least 1.
+    ///
+    /// Available using the `v3_11_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))")?;
+    /// let cells = geom.grid_cells(2, 2)?;
+    ///
+    /// assert_eq!(cells.get_num_geometries()?, 4);
+    /// assert_eq!(
+    ///     cells.get_geometry_n(0)?.to_wkt()?,
+    ///     "POLYGON ((0 0, 5 0, 5 5, 0 5, 0 0))"
+    /// );
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_11_0")]
+    fn grid_cells(&self, nx: usize, ny: usize) -> GResult<Geometry> {
+        if nx < 1 || ny < 1 {
+            return Err(Error::GenericError(
+                "the grid needs at least one column and one row".to_owned(),
+            ));
+        }
+        let extent = self.get_extent()?;
+        let (xmin, ymin, xmax, ymax) = (extent[0], extent[1], extent[2], extent[3]);
+        let x_at = |i: usize| xmin + (xmax - xmin) * i as f64 / nx as f64;
+        let y_at = |j: usize| ymin + (ymax - ymin) * j as f64 / ny as f64;
+
+        let mut cells = Vec::with_capacity(nx * ny);
+        for j in 0..ny {
+            for i in 0..nx {
+                cells.push(Geometry::create_rectangle(
+                    x_at(i),
+                    y_at(j),
+                    x_at(i + 1),
+                    y_at(j + 1),
+                )?);
+            }
+        }
+        Geometry::create_geometry_collection(cells)
+    }
+
     #[cfg(feature = "v3_12_0")]
     fn line_substring(&self, start_fraction: f64, end_fraction: f64) -> GResult<Geometry> {
         with_context(|ctx| unsafe {
@@ -2880,6 +5065,108 @@ impl Geometry {
         })
     }
 
+    /// Polygonizes the linework of `self` and returns its faces without the polygons filling
+    /// their holes.
+    ///
+    /// [`polygonize`](Geometry::polygonize) already gives each face its holes, but it also returns
+    /// the inside of each hole as a polygon on its own. Here, the shells of the faces are tested
+    /// for containment: faces enclosed in an odd number of other faces fill a hole and are
+    /// dropped, the others are kept unchanged. Rings drawn within a hole are islands.
+    ///
+    /// Faces sharing an edge aren't merged. The result is a `Polygon` when a single face is kept,
+    /// a `MultiPolygon` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let linework = Geometry::new_from_wkt(
+    ///     "MULTILINESTRING((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 8 2, 8 8, 2 8, 2 2))",
+    /// )?;
+    ///
+    /// assert_eq!(Geometry::polygonize(&[&linework])?.get_num_geometries()?, 2);
+    ///
+    /// let area = linework.polygonize_with_holes()?;
+    /// assert_eq!(area.get_num_interior_rings()?, 1);
+    /// assert_eq!(area.area()?, 64.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn polygonize_with_holes(&self) -> GResult<Self> {
+        let faces = Self::polygonize(&[self])?;
+        let count = faces.get_num_geometries()?;
+        let shells = (0..count)
+            .map(|n| {
+                let shell = faces.get_geometry_n(n)?.get_exterior_ring()?.clone()?;
+                Self::create_polygon(shell, vec![])
+            })
+            .collect::<GResult<Vec<_>>>()?;
+        let mut kept = Vec::new();
+        for (n, shell) in shells.iter().enumerate() {
+            let mut depth = 0;
+            for (other_n, other) in shells.iter().enumerate() {
+                if other_n != n && other.contains(shell)? {
+                    depth += 1;
+                }
+            }
+            if depth % 2 == 0 {
+                kept.push(faces.get_geometry_n(n)?.clone()?);
+            }
+        }
+        if kept.len() == 1 {
+            return Ok(kept.remove(0));
+        }
+        Self::create_multipolygon(kept)
+    }
+
+    /// Returns the matrix of the distances between each pair of `geoms`: the element `[i][j]` is
+    /// the distance between the `i`th and the `j`th geometries.
+    ///
+    /// The matrix is symmetric, with zeros on its diagonal, so each distance is only computed
+    /// once. That's still `N * (N - 1) / 2` distance computations for `N` geometries, so it's
+    /// only meant for small sets. With the `v3_7_0` feature, pairs involving a geometry of more
+    /// than 64 vertices use [`distance_indexed`](Geom::distance_indexed).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geoms = vec![
+    ///     Geometry::new_from_wkt("POINT(0 0)")?,
+    ///     Geometry::new_from_wkt("POINT(3 4)")?,
+    /// ];
+    ///
+    /// assert_eq!(Geometry::distance_matrix(&geoms)?, vec![vec![0., 5.], vec![5., 0.]]);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn distance_matrix<'a, I: IntoIterator<Item = &'a Geometry>>(
+        geoms: I,
+    ) -> GResult<Vec<Vec<f64>>> {
+        let geoms = geoms.into_iter().collect::<Vec<_>>();
+        #[cfg(feature = "v3_7_0")]
+        let sizes = geoms
+            .iter()
+            .map(|geom| geom.get_num_coordinates())
+            .collect::<GResult<Vec<_>>>()?;
+        let mut matrix = vec![vec![0.; geoms.len()]; geoms.len()];
+        for i in 0..geoms.len() {
+            for j in i + 1..geoms.len() {
+                #[cfg(feature = "v3_7_0")]
+                let distance = if sizes[i].max(sizes[j]) > 64 {
+                    geoms[i].distance_indexed(geoms[j])?
+                } else {
+                    geoms[i].distance(geoms[j])?
+                };
+                #[cfg(not(feature = "v3_7_0"))]
+                let distance = geoms[i].distance(geoms[j])?;
+                matrix[i][j] = distance;
+                matrix[j][i] = distance;
+            }
+        }
+        Ok(matrix)
+    }
+
     pub fn polygonizer_get_cut_edges<T: Borrow<Self>>(&self, geometries: &[T]) -> GResult<Self> {
         with_context(|ctx| unsafe {
             let geoms = geometries
@@ -2963,8 +5250,46 @@ impl Geometry {
         })
     }
 
+    /// Same as [`simplify`](Geometry::simplify), but also returns, in the tuple elements order,
+    /// the number of coordinates of `self` and the number of coordinates of the simplified
+    /// geometry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 1 0.01, 2 0, 3 0.01, 4 0)")?;
+    /// let (simplified, before, after) = geom.simplify_with_stats(0.1)?;
+    ///
+    /// assert_eq!(simplified.to_wkt()?, "LINESTRING (0 0, 4 0)");
+    /// assert_eq!((before, after), (5, 2));
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn simplify_with_stats(&self, tolerance: f64) -> GResult<(Self, usize, usize)> {
+        let simplified = self.simplify(tolerance)?;
+        let input_count = self.get_num_coordinates()?;
+        let output_count = simplified.get_num_coordinates()?;
+        Ok((simplified, input_count, output_count))
+    }
+
     /// Returns a simplified version of the given geometry. It will avoid creating invalid derived
     /// geometries.
+    ///
+    /// The simplification is computed in 2D, but the vertices which are kept are copied from
+    /// `self` along with their Z ordinate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING Z(0 0 1, 1 0.01 2, 2 0 3)")?;
+    /// let simplified = geom.topology_preserve_simplify(0.1)?;
+    ///
+    /// assert_eq!(simplified.to_wkt()?, "LINESTRING Z (0 0 1, 2 0 3)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
     pub fn topology_preserve_simplify(&self, tolerance: f64) -> GResult<Self> {
         with_context(|ctx| unsafe {
             let ptr = nullcheck!(GEOSTopologyPreserveSimplify_r(
@@ -2976,6 +5301,124 @@ impl Geometry {
         })
     }
 
+    /// Smooths `self` by buffering it by `buffer_distance`, simplifying the result with
+    /// [`topology_preserve_simplify`](Geometry::topology_preserve_simplify) and buffering it back
+    /// by `-buffer_distance` to get close to the original size. Buffers use 8 segments per
+    /// quadrant.
+    ///
+    /// The area isn't preserved: the outward buffer fills concave parts narrower than twice
+    /// `buffer_distance`, which the inward buffer doesn't restore, and the simplification can
+    /// move the boundary by up to `simplify_tolerance`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "POLYGON((0 0, 10 0, 10 10, 9 9.8, 8 10, 7 9.8, 6 10, 5 9.8, 4 10, 3 9.8, 2 10, \
+    ///               1 9.8, 0 10, 0 0))",
+    /// )?;
+    /// let smoothed = geom.smooth(0.5, 0.3)?;
+    ///
+    /// assert!(smoothed.get_num_coordinates()? < geom.get_num_coordinates()?);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn smooth(&self, buffer_distance: f64, simplify_tolerance: f64) -> GResult<Self> {
+        self.buffer(buffer_distance, 8)?
+            .topology_preserve_simplify(simplify_tolerance)?
+            .buffer(-buffer_distance, 8)
+    }
+
+    /// Same as [`topology_preserve_simplify`](Geometry::topology_preserve_simplify), but the
+    /// tolerance is given as a `fraction` of the diagonal of the envelope of `self`, so the same
+    /// `fraction` gives similar results whatever the scale of `self`.
+    ///
+    /// `fraction` must be strictly between 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING(0 0, 30 0.1, 60 0, 60 80)")?;
+    /// let simplified = geom.simplify_relative(0.01)?;
+    ///
+    /// assert_eq!(simplified.to_wkt()?, "LINESTRING (0 0, 60 0, 60 80)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn simplify_relative(&self, fraction: f64) -> GResult<Self> {
+        if !(fraction > 0. && fraction < 1.) {
+            return Err(Error::GenericError(
+                "fraction must be strictly between 0 and 1".to_owned(),
+            ));
+        }
+        let corners = collect_xy(&self.envelope()?)?;
+        let (mut xmin, mut ymin) = (f64::INFINITY, f64::INFINITY);
+        let (mut xmax, mut ymax) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for (x, y) in corners {
+            xmin = xmin.min(x);
+            ymin = ymin.min(y);
+            xmax = xmax.max(x);
+            ymax = ymax.max(y);
+        }
+        let diagonal = if xmin <= xmax {
+            (xmax - xmin).hypot(ymax - ymin)
+        } else {
+            0.
+        };
+        self.topology_preserve_simplify(fraction * diagonal)
+    }
+
+    /// Same as [`simplify`](Geometry::simplify), but rejects results which lose the parts of
+    /// `self` at least `min_width` wide, like a narrow neck collapsing or a polygon disappearing.
+    ///
+    /// These parts are what's left after a [`buffer`](Geom::buffer) of `-min_width / 2`. A
+    /// result is rejected when it doesn't have as many of them as `self`, or when they moved
+    /// farther than the tolerance (by [`hausdorff_distance`](Geom::hausdorff_distance)). So a
+    /// neck at least `min_width` wide stays at least that wide, while the parts of `self` which
+    /// are narrower than `min_width` (or lines) are simplified freely.
+    ///
+    /// When the result is rejected, the tolerance is halved and the simplification is tried
+    /// again, up to 8 times. If none of the results is accepted, an unsimplified copy of `self`
+    /// is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 1, 0 1, 0 0))")?;
+    ///
+    /// // With a tolerance of 2 (or 1), the polygon collapses: the tolerance goes down to 0.5.
+    /// assert_eq!(geom.simplify_min_feature(2., 0.5)?.area()?, 10.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn simplify_min_feature(&self, tolerance: f64, min_width: f64) -> GResult<Self> {
+        let wide_parts = |geom: &Geometry| -> GResult<(Geometry, usize)> {
+            let wide = geom.buffer(-min_width / 2., 8)?;
+            let count = if wide.is_empty()? {
+                0
+            } else {
+                wide.get_num_geometries()?
+            };
+            Ok((wide, count))
+        };
+        let (wide, count) = wide_parts(self)?;
+        let mut tolerance = tolerance;
+        for _ in 0..=8 {
+            let simplified = self.simplify(tolerance)?;
+            let (simplified_wide, simplified_count) = wide_parts(&simplified)?;
+            if simplified_count == count
+                && (count == 0 || wide.hausdorff_distance(&simplified_wide)? <= tolerance)
+            {
+                return Ok(simplified);
+            }
+            tolerance /= 2.;
+        }
+        Geom::clone(self)
+    }
+
     /// Set SRID of `self`.
     ///
     /// # Example
@@ -3316,6 +5759,67 @@ impl Geometry {
         create_multi_geom(points, GeometryTypes::MultiPoint)
     }
 
+    /// Creates a multi point geometry from a slice of `(x, y)` coordinates.
+    ///
+    /// Each point is still created separately (with `GEOSGeom_createPointFromXY`), the only
+    /// saving is that the GEOS context is fetched once for all of them, instead of once per
+    /// point.
+    ///
+    /// Available using the `v3_8_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, GeometryTypes};
+    ///
+    /// let geom = Geometry::multipoint_from_coords(&[(1., 2.), (3., 4.)])?;
+    ///
+    /// assert_eq!(geom.geometry_type()?, GeometryTypes::MultiPoint);
+    /// assert_eq!(geom.get_num_geometries()?, 2);
+    /// assert_eq!(geom.get_geometry_n(1)?.to_wkt()?, "POINT (3 4)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_8_0")]
+    pub fn multipoint_from_coords(coords: &[(f64, f64)]) -> GResult<Self> {
+        let points = with_context(|ctx| {
+            coords
+                .iter()
+                .map(|&(x, y)| unsafe {
+                    let ptr = nullcheck!(GEOSGeom_createPointFromXY_r(ctx.as_raw(), x, y))?;
+                    Ok(Self::new_from_raw(ptr))
+                })
+                .collect::<GResult<Vec<_>>>()
+        })?;
+        create_multi_geom(points, GeometryTypes::MultiPoint)
+    }
+
+    /// Returns the minimum bounding circle of the given `(x, y)` coordinates, as
+    /// `(center_x, center_y, radius)`.
+    ///
+    /// Available using the `v3_8_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::Geometry;
+    ///
+    /// let circle = Geometry::enclosing_circle(&[(0., 0.), (4., 0.), (0., 3.)])?;
+    ///
+    /// assert_eq!(circle, (2., 1.5, 2.5));
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_8_0")]
+    pub fn enclosing_circle(coords: &[(f64, f64)]) -> GResult<(f64, f64, f64)> {
+        if coords.is_empty() {
+            return Err(Error::ImpossibleOperation(
+                "cannot compute the enclosing circle of no coordinates".to_owned(),
+            ));
+        }
+        let points = Self::multipoint_from_coords(coords)?;
+        let (center, radius) = minimum_bounding_circle(&points)?;
+        Ok((center.get_x()?, center.get_y()?, radius))
+    }
+
     /// Create a multicurve geometry.
     ///
     /// # Example
@@ -13,10 +13,13 @@ pub use wkt;
 pub(crate) mod functions;
 
 pub use buffer_params::{BufferParams, BufferParamsBuilder};
+pub use buffered_geometry::BufferedGeometry;
 pub use context_handle::{ContextHandle, HandlerCallback};
 pub use coord_seq::CoordSeq;
 #[cfg(feature = "v3_10_0")]
 pub use enums::MakeValidMethod;
+#[cfg(feature = "v3_9_0")]
+pub use enums::OverlayOp;
 #[cfg(feature = "v3_6_0")]
 pub use enums::Precision;
 pub use enums::{
@@ -32,11 +35,13 @@ pub use geometry::{ConstGeometry, Geom, Geometry};
 #[cfg(feature = "v3_10_0")]
 pub use make_valid_params::{MakeValidParams, MakeValidParamsBuilder};
 pub use prepared_geometry::PreparedGeometry;
+pub use prepared_mask::PreparedMask;
 pub use spatial_index::{STRtree, SpatialIndex};
 pub use wkb_writer::WKBWriter;
 pub use wkt_writer::WKTWriter;
 
 mod buffer_params;
+mod buffered_geometry;
 mod context_handle;
 mod coord_seq;
 mod error;
@@ -48,6 +53,7 @@ mod geometry;
 #[cfg(feature = "v3_10_0")]
 mod make_valid_params;
 mod prepared_geometry;
+mod prepared_mask;
 mod spatial_index;
 #[cfg(feature = "geo")]
 pub mod to_geo;
@@ -0,0 +1,54 @@
+use crate::{GResult, Geom, PreparedGeometry};
+
+/// `PreparedMask` keeps a [`Geometry`](crate::Geometry) together with its
+/// [`PreparedGeometry`], for the functions which need both, like
+/// [`area_within_prepared`](Geom::area_within_prepared).
+///
+/// Since the prepared geometry is created from the wrapped one, they can't get mixed up.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, Geometry, PreparedMask};
+///
+/// let mask = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))")?;
+/// let prepared_mask = PreparedMask::new(&mask)?;
+/// let geom = Geometry::new_from_wkt("POLYGON((8 2, 12 2, 12 6, 8 6, 8 2))")?;
+///
+/// assert_eq!(geom.area_within_prepared(&prepared_mask)?, 8.);
+/// # Ok::<(), geos::Error>(())
+/// ```
+pub struct PreparedMask<'a, G: Geom> {
+    geometry: &'a G,
+    prepared: PreparedGeometry<'a>,
+}
+
+impl<'a, G: Geom> PreparedMask<'a, G> {
+    /// Prepares `geometry` and wraps both.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geometry, PreparedMask};
+    ///
+    /// let mask = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))")?;
+    /// let prepared_mask = PreparedMask::new(&mask)?;
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn new(geometry: &'a G) -> GResult<Self> {
+        Ok(PreparedMask {
+            geometry,
+            prepared: PreparedGeometry::new(geometry)?,
+        })
+    }
+
+    /// Returns the wrapped geometry.
+    pub fn geometry(&self) -> &'a G {
+        self.geometry
+    }
+
+    /// Returns the prepared form of the wrapped geometry.
+    pub fn prepared(&self) -> &PreparedGeometry<'a> {
+        &self.prepared
+    }
+}
@@ -1,5 +1,5 @@
 use crate::enums::GeometryTypes;
-use crate::{Geom, Geometry, PreparedGeometry};
+use crate::{CoordSeq, Geom, Geometry, PreparedGeometry};
 
 #[test]
 fn test_relationship() {
@@ -518,3 +518,1329 @@ fn test_make_valid_keep_collapsed_false() {
 
     assert!(valid_geom.is_valid().unwrap());
 }
+
+#[test]
+#[cfg(feature = "v3_8_0")]
+fn test_bounding_circle_radius() {
+    // Right triangle: the circumcircle is centered on the hypotenuse.
+    let geom = Geometry::new_from_wkt("MULTIPOINT((0 0), (4 0), (0 3))").unwrap();
+    assert_almost_eq(geom.bounding_circle_radius().unwrap(), 2.5);
+}
+
+#[test]
+#[cfg(feature = "v3_12_0")]
+fn test_fix_coverage() {
+    let coverage = Geometry::new_from_wkt(
+        "MULTIPOLYGON(((0 0, 1 0, 1 1, 0 1, 0 0)), ((0.999 0, 2 0, 2 1, 0.999 1, 0.999 0)))",
+    )
+    .unwrap();
+    assert!(!coverage.coverage_is_valid(0.01).unwrap().0);
+
+    let fixed = coverage.fix_coverage(0.01).unwrap();
+    assert_eq!(fixed.geometry_type(), Ok(GeometryTypes::MultiPolygon));
+    assert_eq!(fixed.get_num_geometries(), Ok(2));
+    assert!(fixed.coverage_is_valid(0.01).unwrap().0);
+}
+
+#[test]
+fn test_solidity() {
+    let square = Geometry::new_from_wkt("POLYGON((0 0, 5 0, 5 5, 0 5, 0 0))").unwrap();
+    assert_almost_eq(square.solidity().unwrap(), 1.);
+
+    let star =
+        Geometry::new_from_wkt("POLYGON((0 10, 1 1, 10 0, 1 -1, 0 -10, -1 -1, -10 0, -1 1, 0 10))")
+            .unwrap();
+    assert_almost_eq(star.solidity().unwrap(), 0.2);
+
+    let point = Geometry::new_from_wkt("POINT(1 1)").unwrap();
+    assert!(point.solidity().is_err());
+}
+
+#[test]
+#[cfg(feature = "v3_8_0")]
+fn test_multipoint_from_coords() {
+    let coords = (0..1000)
+        .map(|i| (f64::from(i), f64::from(i) * 2.))
+        .collect::<Vec<_>>();
+    let multi_point = Geometry::multipoint_from_coords(&coords).unwrap();
+    assert_eq!(multi_point.geometry_type(), Ok(GeometryTypes::MultiPoint));
+    assert_eq!(multi_point.get_num_geometries(), Ok(1000));
+
+    let last = multi_point.get_geometry_n(999).unwrap();
+    assert_eq!(last.get_x(), Ok(999.));
+    assert_eq!(last.get_y(), Ok(1998.));
+}
+
+#[test]
+fn test_simplify_with_stats() {
+    let geom = Geometry::new_from_wkt(
+        "POLYGON((0 0, 5 0.1, 10 0, 10.1 5, 10 10, 5 9.9, 0 10, 0.1 5, 0 0))",
+    )
+    .unwrap();
+    let (simplified, input_count, output_count) = geom.simplify_with_stats(0.5).unwrap();
+
+    assert!(output_count <= input_count);
+    assert_eq!(input_count, geom.get_num_coordinates().unwrap());
+    assert_eq!(output_count, simplified.get_num_coordinates().unwrap());
+}
+
+#[test]
+fn test_project_with_offset() {
+    let line = Geometry::new_from_wkt("LINESTRING (0 0, 10 0)").unwrap();
+    let point = Geometry::new_from_wkt("POINT (5 2)").unwrap();
+
+    let (measure, offset) = line.project_with_offset(&point).unwrap();
+    assert_almost_eq(measure, 5.);
+    assert_almost_eq(offset, 2.);
+
+    let polygon = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 0))").unwrap();
+    assert!(polygon.project_with_offset(&point).is_err());
+}
+
+#[test]
+fn test_has_finite_coordinates() {
+    let polygon = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 0))").unwrap();
+    assert!(polygon.has_finite_coordinates().unwrap());
+
+    let coords = CoordSeq::new_from_vec(&[&[0., 0.], &[1., 1.], &[f64::NAN, 2.]]).unwrap();
+    let line = Geometry::create_line_string(coords).unwrap();
+    assert!(!line.has_finite_coordinates().unwrap());
+
+    let coords = CoordSeq::new_from_vec(&[&[0., 0., 0.], &[1., 1., f64::INFINITY]]).unwrap();
+    let line = Geometry::create_line_string(coords).unwrap();
+    let collection = Geometry::create_geometry_collection(vec![polygon, line]).unwrap();
+    assert!(!collection.has_finite_coordinates().unwrap());
+}
+
+#[test]
+fn test_diameter_endpoints() {
+    let geom =
+        Geometry::new_from_wkt("MULTIPOINT ((1 1), (-3 -4), (2 0), (0 2), (9 8), (3 3))").unwrap();
+    let (a, b) = geom.diameter_endpoints().unwrap();
+    let mut endpoints = [
+        (a.get_x().unwrap(), a.get_y().unwrap()),
+        (b.get_x().unwrap(), b.get_y().unwrap()),
+    ];
+    endpoints.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(endpoints, [(-3., -4.), (9., 8.)]);
+
+    let empty = Geometry::new_from_wkt("POINT EMPTY").unwrap();
+    assert!(empty.diameter_endpoints().is_err());
+}
+
+#[test]
+fn test_offset_both() {
+    use crate::JoinStyle;
+
+    let line = Geometry::new_from_wkt("LINESTRING (0 0, 10 0)").unwrap();
+    let (left, right) = line.offset_both(1., 8, JoinStyle::Mitre, 5.).unwrap();
+
+    for (offset, y) in [(left, 1.), (right, -1.)] {
+        let coords = offset.get_coord_seq().unwrap();
+        assert_eq!(coords.size().unwrap(), 2);
+        for i in 0..2 {
+            assert_almost_eq(coords.get_y(i).unwrap(), y);
+        }
+    }
+}
+
+#[test]
+fn test_segment_midpoints() {
+    let line = Geometry::new_from_wkt("LINESTRING (0 0, 2 0, 2 2)").unwrap();
+    let midpoints = line.segment_midpoints().unwrap();
+    let expected = Geometry::new_from_wkt("MULTIPOINT ((1 0), (2 1))").unwrap();
+    assert!(midpoints.equals_exact(&expected, 0.).unwrap());
+
+    let multi_line =
+        Geometry::new_from_wkt("MULTILINESTRING ((0 0, 2 0), (4 4, 4 6, 6 6))").unwrap();
+    let midpoints = multi_line.segment_midpoints().unwrap();
+    let expected = Geometry::new_from_wkt("MULTIPOINT ((1 0), (4 5), (5 6))").unwrap();
+    assert!(midpoints.equals_exact(&expected, 0.).unwrap());
+
+    let polygon = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 0))").unwrap();
+    assert!(polygon.segment_midpoints().is_err());
+}
+
+#[test]
+fn test_equals_normalized() {
+    let geom1 = Geometry::new_from_wkt(
+        "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))",
+    )
+    .unwrap();
+    let geom2 = Geometry::new_from_wkt(
+        "MULTIPOLYGON (((6 6, 6 5, 5 5, 6 6)), ((1 1, 0 1, 0 0, 1 0, 1 1)))",
+    )
+    .unwrap();
+    assert!(geom1.equals_normalized(&geom2).unwrap());
+
+    let geom3 = Geometry::new_from_wkt(
+        "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 1, 0 0)), ((5 5, 6 5, 6 6, 5.5 5.5, 5 5)))",
+    )
+    .unwrap();
+    assert!(geom1.equals(&geom3).unwrap());
+    assert!(!geom1.equals_normalized(&geom3).unwrap());
+}
+
+#[test]
+#[cfg(feature = "v3_11_0")]
+fn test_grid_cells() {
+    let geom = Geometry::new_from_wkt("POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap();
+    let cells = geom.grid_cells(2, 2).unwrap();
+
+    assert_eq!(cells.geometry_type(), Ok(GeometryTypes::GeometryCollection));
+    assert_eq!(cells.get_num_geometries(), Ok(4));
+    for n in 0..4 {
+        let cell = cells.get_geometry_n(n).unwrap();
+        assert_almost_eq(cell.area().unwrap(), 25.);
+        assert!(geom.covers(&cell).unwrap());
+    }
+    assert!(cells.unary_union().unwrap().equals(&geom).unwrap());
+
+    assert!(geom.grid_cells(0, 2).is_err());
+    assert!(geom.grid_cells(2, 0).is_err());
+}
+
+#[test]
+#[cfg(feature = "v3_9_0")]
+fn test_overlay() {
+    use crate::OverlayOp;
+
+    let geom1 = Geometry::new_from_wkt("POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap();
+    let geom2 =
+        Geometry::new_from_wkt("POLYGON ((5.2 5.2, 15 5.2, 15 15, 5.2 15, 5.2 5.2))").unwrap();
+
+    let check = |op, grid_size, expected: Geometry| {
+        let overlay = geom1.overlay(&geom2, op, grid_size).unwrap();
+        assert!(overlay.equals_exact(&expected, 0.).unwrap(), "{op:?}");
+    };
+    check(
+        OverlayOp::Intersection,
+        None,
+        geom1.intersection(&geom2).unwrap(),
+    );
+    check(OverlayOp::Union, None, geom1.union(&geom2).unwrap());
+    check(
+        OverlayOp::Difference,
+        None,
+        geom1.difference(&geom2).unwrap(),
+    );
+    check(
+        OverlayOp::SymDifference,
+        None,
+        geom1.sym_difference(&geom2).unwrap(),
+    );
+    check(
+        OverlayOp::Intersection,
+        Some(1.),
+        geom1.intersection_prec(&geom2, 1.).unwrap(),
+    );
+    check(
+        OverlayOp::Union,
+        Some(1.),
+        geom1.union_prec(&geom2, 1.).unwrap(),
+    );
+    check(
+        OverlayOp::Difference,
+        Some(1.),
+        geom1.difference_prec(&geom2, 1.).unwrap(),
+    );
+    check(
+        OverlayOp::SymDifference,
+        Some(1.),
+        geom1.sym_difference_prec(&geom2, 1.).unwrap(),
+    );
+}
+
+#[test]
+fn test_topology_preserve_simplify_keeps_z() {
+    let geom =
+        Geometry::new_from_wkt("LINESTRING Z (0 0 10, 1 0.01 20, 2 0 30, 3 5 40, 4 0 50)").unwrap();
+    let simplified = geom.topology_preserve_simplify(0.1).unwrap();
+    assert!(simplified.has_z().unwrap());
+
+    let coords = simplified.get_coord_seq().unwrap();
+    let kept: Vec<_> = (0..coords.size().unwrap())
+        .map(|i| {
+            (
+                coords.get_x(i).unwrap(),
+                coords.get_y(i).unwrap(),
+                coords.get_z(i).unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        kept,
+        [(0., 0., 10.), (2., 0., 30.), (3., 5., 40.), (4., 0., 50.)]
+    );
+}
+
+#[test]
+fn test_mutual_nearest_points() {
+    let l_shape = Geometry::new_from_wkt("POLYGON ((0 0, 4 0, 4 1, 1 1, 1 4, 0 4, 0 0))").unwrap();
+    let point = Geometry::new_from_wkt("POINT (3 3)").unwrap();
+
+    let (on_l_shape, on_point) = l_shape.mutual_nearest_points(&point).unwrap();
+    assert!(!on_l_shape.equals(&on_point).unwrap());
+    assert!(on_point.equals(&point).unwrap());
+    assert!(l_shape.intersects(&on_l_shape).unwrap());
+    assert_almost_eq(on_l_shape.distance(&point).unwrap(), 2.);
+}
+
+#[test]
+fn test_validate_members() {
+    let geom = Geometry::new_from_wkt(
+        "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((10 10, 12 12, 12 10, 10 12, 10 10)), \
+         ((5 5, 6 5, 6 6, 5 5)))",
+    )
+    .unwrap();
+    let results = geom.validate_members().unwrap();
+
+    assert_eq!(results.len(), 3);
+    let invalid: Vec<_> = results.iter().filter(|(_, valid, _)| !valid).collect();
+    assert_eq!(invalid.len(), 1);
+    assert_eq!(invalid[0].0, 1);
+    assert!(invalid[0]
+        .2
+        .as_deref()
+        .unwrap()
+        .contains("Self-intersection"));
+    assert!(results[0].2.is_none() && results[2].2.is_none());
+}
+
+#[test]
+fn test_interpolate_equidistant() {
+    let line = Geometry::new_from_wkt("LINESTRING (0 0, 5 0, 5 5)").unwrap();
+    let markers = line.interpolate_equidistant(2.5).unwrap();
+
+    let measures: Vec<f64> = markers.iter().map(|(_, measure)| *measure).collect();
+    assert_eq!(measures, [0., 2.5, 5., 7.5, 10.]);
+    for (point, measure) in &markers {
+        assert_eq!(point.geometry_type(), Ok(GeometryTypes::Point));
+        if *measure > 0. {
+            assert_almost_eq(line.project(point).unwrap(), *measure);
+        }
+    }
+
+    // 0.3 / 0.1 is 2.9999999999999996.
+    let short = Geometry::new_from_wkt("LINESTRING (0 0, 0.3 0)").unwrap();
+    let markers = short.interpolate_equidistant(0.1).unwrap();
+    assert_eq!(markers.len(), 4);
+    assert_almost_eq(markers[3].1, 0.3);
+    assert!(markers[3]
+        .0
+        .equals(&short.get_end_point().unwrap())
+        .unwrap());
+
+    assert!(line.interpolate_equidistant(0.).is_err());
+    assert!(line.interpolate_equidistant(-1.).is_err());
+    assert!(line.interpolate_equidistant(f64::NAN).is_err());
+}
+
+#[test]
+fn test_self_snap() {
+    let polygon =
+        Geometry::new_from_wkt("POLYGON ((0 0, 5 0, 10 0, 10 10, 5 0.001, 0 10, 0 0))").unwrap();
+    let snapped = polygon.self_snap(0.01).unwrap();
+
+    assert_eq!(snapped.get_num_coordinates(), Ok(7));
+    let unique_points = snapped.extract_unique_points().unwrap();
+    assert_eq!(unique_points.get_num_geometries(), Ok(5));
+    let exterior = snapped
+        .get_exterior_ring()
+        .unwrap()
+        .get_coord_seq()
+        .unwrap();
+    assert_eq!(exterior.get_x(4), Ok(5.));
+    assert_eq!(exterior.get_y(4), Ok(0.));
+
+    // A smaller tolerance leaves the polygon untouched.
+    let snapped = polygon.self_snap(0.0001).unwrap();
+    assert!(snapped.equals_exact(&polygon, 0.).unwrap());
+}
+
+#[test]
+fn test_interpolate_3d() {
+    let line = Geometry::new_from_wkt("LINESTRING Z (0 0 0, 10 0 10)").unwrap();
+    let point = line.interpolate_3d(5.).unwrap();
+    assert!(point.has_z().unwrap());
+    assert_eq!(point.get_x(), Ok(5.));
+    assert_eq!(point.get_y(), Ok(0.));
+    assert_almost_eq(point.get_coord_seq().unwrap().get_z(0).unwrap(), 5.);
+
+    // Negative distances are measured from the end.
+    let point = line.interpolate_3d(-2.).unwrap();
+    assert_almost_eq(point.get_coord_seq().unwrap().get_z(0).unwrap(), 8.);
+
+    let line_2d = Geometry::new_from_wkt("LINESTRING (0 0, 10 0)").unwrap();
+    assert!(line_2d.interpolate_3d(5.).is_err());
+}
+
+#[test]
+fn test_approx_equal() {
+    let geom1 = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))").unwrap();
+    let geom2 = Geometry::new_from_wkt(
+        "POLYGON ((1.000000001 1, 1.000000001 0, 0.000000001 0, 0.000000001 1, 1.000000001 1))",
+    )
+    .unwrap();
+
+    assert!(geom1.approx_equal(&geom2, 1e-6, 0.).unwrap());
+    assert!(!geom1.approx_equal(&geom2, 1e-12, 0.).unwrap());
+
+    let with_hole = Geometry::new_from_wkt(
+        "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0), (0.2 0.2, 0.4 0.2, 0.4 0.4, 0.2 0.2))",
+    )
+    .unwrap();
+    assert!(!geom1.approx_equal(&with_hole, 1., 1.).unwrap());
+}
+
+#[test]
+fn test_largest_part() {
+    let geom = Geometry::new_from_wkt(
+        "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 1, 0 0)), ((10 10, 20 10, 20 20, 10 20, 10 10)), \
+         ((30 30, 32 30, 32 32, 30 32, 30 30)))",
+    )
+    .unwrap();
+    let largest = geom.largest_part().unwrap();
+    assert_eq!(largest.geometry_type(), Ok(GeometryTypes::Polygon));
+    assert_almost_eq(largest.area().unwrap(), 100.);
+
+    let lines = Geometry::new_from_wkt("MULTILINESTRING ((0 0, 1 0), (0 0, 0 5))").unwrap();
+    assert_almost_eq(lines.largest_part().unwrap().length().unwrap(), 5.);
+
+    let points = Geometry::new_from_wkt("MULTIPOINT ((3 4), (1 2))").unwrap();
+    assert_eq!(points.largest_part().unwrap().get_x(), Ok(3.));
+
+    let empty = Geometry::new_from_wkt("MULTIPOLYGON EMPTY").unwrap();
+    assert!(empty.largest_part().is_err());
+}
+
+#[test]
+fn test_fill_small_holes() {
+    let geom = Geometry::new_from_wkt(
+        "MULTIPOLYGON (((0 0, 30 0, 30 30, 0 30, 0 0), (1 1, 1.5 1, 1.5 1.2, 1 1.2, 1 1), \
+         (10 10, 20 10, 20 20, 10 20, 10 10)), ((40 40, 41 40, 41 41, 40 40)))",
+    )
+    .unwrap();
+    let filled = geom.fill_small_holes(1.).unwrap();
+
+    assert_eq!(filled.geometry_type(), Ok(GeometryTypes::MultiPolygon));
+    assert_eq!(filled.get_num_geometries(), Ok(2));
+    let polygon = filled.get_geometry_n(0).unwrap();
+    assert_eq!(polygon.get_num_interior_rings(), Ok(1));
+    assert_almost_eq(polygon.area().unwrap(), 900. - 100.);
+
+    let line = Geometry::new_from_wkt("LINESTRING (0 0, 1 1)").unwrap();
+    assert!(line.fill_small_holes(1.).is_err());
+}
+
+#[test]
+#[cfg(feature = "v3_10_0")]
+fn test_densify_keeps_original_vertices() {
+    use crate::functions::for_each_coord_seq;
+
+    fn vertices(geom: &Geometry) -> Vec<(f64, f64)> {
+        let mut vertices = Vec::new();
+        for_each_coord_seq(geom, &mut |coords| {
+            for i in 0..coords.size()? {
+                vertices.push((coords.get_x(i)?, coords.get_y(i)?));
+            }
+            Ok(())
+        })
+        .unwrap();
+        vertices
+    }
+
+    for wkt in [
+        "LINESTRING (0.1 0.3, 7.7 1.9, 3.3 9.1)",
+        "POLYGON ((0 0, 10.3 0, 10.3 10.7, 0 10.7, 0 0), (1.1 1.1, 3.7 1.1, 3.7 2.9, 1.1 1.1))",
+    ] {
+        let geom = Geometry::new_from_wkt(wkt).unwrap();
+        let densified = geom.densify(0.7).unwrap();
+        let input = vertices(&geom);
+        let output = vertices(&densified);
+
+        assert!(output.len() > input.len());
+        // Original vertices are kept, exactly and in the same order.
+        let mut remaining = output.iter();
+        for vertex in &input {
+            assert!(
+                remaining.any(|v| v == vertex),
+                "{vertex:?} missing in {wkt}"
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "v3_10_0", feature = "serde_json"))]
+fn test_to_geojson_value() {
+    let point = Geometry::new_from_wkt("POINT (1.5 -2)").unwrap();
+    let value = point.to_geojson_value(2).unwrap();
+
+    assert_eq!(value["type"], "Point");
+    assert_eq!(value["coordinates"], serde_json::json!([1.5, -2.0]));
+    assert_eq!(value, point.to_geojson_value(-1).unwrap());
+}
+
+#[test]
+fn test_shared_boundary_length() {
+    let square1 = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))").unwrap();
+    let square2 = Geometry::new_from_wkt("POLYGON ((1 0, 2 0, 2 1, 1 1, 1 0))").unwrap();
+    let corner = Geometry::new_from_wkt("POLYGON ((1 1, 2 1, 2 2, 1 2, 1 1))").unwrap();
+
+    assert_almost_eq(square1.shared_boundary_length(&square2).unwrap(), 1.);
+    assert_almost_eq(square2.shared_boundary_length(&square1).unwrap(), 1.);
+    assert_eq!(square1.shared_boundary_length(&corner), Ok(0.));
+}
+
+#[test]
+fn test_is_convex() {
+    let square = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))").unwrap();
+    assert!(square.is_convex().unwrap());
+
+    // Collinear vertices don't make a polygon concave.
+    let square = Geometry::new_from_wkt("POLYGON ((0 0, 0.5 0, 1 0, 1 1, 0 1, 0 0))").unwrap();
+    assert!(square.is_convex().unwrap());
+
+    let l_shape = Geometry::new_from_wkt("POLYGON ((0 0, 4 0, 4 1, 1 1, 1 4, 0 4, 0 0))").unwrap();
+    assert!(!l_shape.is_convex().unwrap());
+
+    let with_hole =
+        Geometry::new_from_wkt("POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1))")
+            .unwrap();
+    assert!(!with_hole.is_convex().unwrap());
+
+    // The hole is far below the area tolerance, it still isn't convex.
+    let with_tiny_hole = Geometry::new_from_wkt(
+        "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 1.000001 1, 1.000001 1.000001, 1 1))",
+    )
+    .unwrap();
+    assert!(!with_tiny_hole.is_convex().unwrap());
+
+    let multi_polygon = Geometry::new_from_wkt("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)))").unwrap();
+    assert!(multi_polygon.is_convex().is_err());
+}
+
+#[test]
+fn test_smooth() {
+    // A 20x10 rectangle whose top edge is a saw with 40 teeth of 0.2 height.
+    let mut top: Vec<String> = (0..=80)
+        .rev()
+        .map(|i| format!("{} {}", f64::from(i) * 0.25, 10. - 0.2 * f64::from(i % 2)))
+        .collect();
+    top.insert(0, "20 0".to_owned());
+    let wkt = format!("POLYGON ((0 0, {}, 0 0))", top.join(", "));
+    let jagged = Geometry::new_from_wkt(&wkt).unwrap();
+    assert!(jagged.is_valid().unwrap());
+
+    let smoothed = jagged.smooth(0.5, 0.3).unwrap();
+    assert_eq!(smoothed.geometry_type(), Ok(GeometryTypes::Polygon));
+    assert!(smoothed.get_num_coordinates().unwrap() * 4 < jagged.get_num_coordinates().unwrap());
+    let area_ratio = smoothed.area().unwrap() / jagged.area().unwrap();
+    assert!(area_ratio > 0.95 && area_ratio < 1.05);
+}
+
+#[test]
+fn test_self_intersection_count() {
+    let bowtie = Geometry::new_from_wkt("POLYGON ((0 0, 2 2, 2 0, 0 2, 0 0))").unwrap();
+    assert!(bowtie.self_intersection_count().unwrap() >= 1);
+
+    let square = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))").unwrap();
+    assert_eq!(square.self_intersection_count(), Ok(0));
+
+    let line =
+        Geometry::new_from_wkt("LINESTRING (0 0, 4 0, 4 4, 2 4, 2 -2, 1 -2, 1 5, 3 5, 3 -1)")
+            .unwrap();
+    assert_eq!(line.self_intersection_count(), Ok(4));
+
+    let point = Geometry::new_from_wkt("POINT (0 0)").unwrap();
+    assert!(point.self_intersection_count().is_err());
+}
+
+#[test]
+fn test_clip_by_circle() {
+    let square =
+        Geometry::new_from_wkt("POLYGON ((-10 -10, 10 -10, 10 10, -10 10, -10 -10))").unwrap();
+    let clipped = square.clip_by_circle(2., 3., 1., 16).unwrap();
+
+    let area = clipped.area().unwrap();
+    assert!((area - std::f64::consts::PI).abs() < 0.01);
+    assert!(square.contains(&clipped).unwrap());
+
+    // Clipping near an edge only keeps the part inside the square.
+    let clipped = square.clip_by_circle(10., 0., 1., 16).unwrap();
+    assert!((clipped.area().unwrap() - std::f64::consts::FRAC_PI_2).abs() < 0.01);
+
+    assert!(square.clip_by_circle(0., 0., 0., 16).is_err());
+    assert!(square.clip_by_circle(0., 0., -1., 16).is_err());
+}
+
+#[test]
+#[cfg(feature = "v3_10_0")]
+fn test_densify_capped() {
+    let line = Geometry::new_from_wkt("LINESTRING (0 0, 100000 0, 100000 100000)").unwrap();
+
+    assert!(line.densify_capped(0.01, 1_000).is_err());
+
+    let densified = line.densify_capped(100., 10_000).unwrap();
+    assert!(densified.get_num_coordinates().unwrap() <= 10_000);
+    assert_eq!(
+        densified.get_num_coordinates().unwrap(),
+        line.densify(100.).unwrap().get_num_coordinates().unwrap()
+    );
+
+    assert!(line.densify_capped(0., 10_000).is_err());
+}
+
+#[test]
+fn test_centroid_distance() {
+    let square1 = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))").unwrap();
+    let square2 = Geometry::new_from_wkt("POLYGON ((10 0, 11 0, 11 1, 10 1, 10 0))").unwrap();
+    assert_almost_eq(square1.centroid_distance(&square2).unwrap(), 10.);
+
+    let empty = Geometry::new_from_wkt("POLYGON EMPTY").unwrap();
+    assert!(square1.centroid_distance(&empty).is_err());
+    assert!(empty.centroid_distance(&square1).is_err());
+}
+
+#[test]
+fn test_buffered_geometry_cache() {
+    use crate::BufferedGeometry;
+
+    let geom = Geometry::new_from_wkt("POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap();
+    let mut buffered = BufferedGeometry::new(&geom);
+
+    let area = buffered.buffer(2., 8).unwrap().area().unwrap();
+    assert_eq!(buffered.buffer(2., 8).unwrap().area(), Ok(area));
+    assert_eq!(buffered.computed, 1);
+
+    // Other parameters replace the single cached entry.
+    assert!(buffered.buffer(1., 8).unwrap().area().unwrap() < area);
+    assert_eq!(buffered.computed, 2);
+    buffered.buffer(1., 4).unwrap();
+    assert_eq!(buffered.computed, 3);
+    buffered.buffer(2., 8).unwrap();
+    assert_eq!(buffered.computed, 4);
+
+    buffered.clear();
+    buffered.buffer(2., 8).unwrap();
+    assert_eq!(buffered.computed, 5);
+}
+
+#[test]
+fn test_simplify_relative() {
+    let small = Geometry::new_from_wkt(
+        "POLYGON ((0 0, 0.5 0.01, 1 0, 1.01 0.5, 1 1, 0.5 0.98, 0 1, 0.02 0.5, 0 0))",
+    )
+    .unwrap();
+    let large = Geometry::new_from_wkt(
+        "POLYGON ((0 0, 500 10, 1000 0, 1010 500, 1000 1000, 500 980, 0 1000, 20 500, 0 0))",
+    )
+    .unwrap();
+
+    for fraction in [0.005, 0.012, 0.05] {
+        let small_simplified = small.simplify_relative(fraction).unwrap();
+        let large_simplified = large.simplify_relative(fraction).unwrap();
+
+        // The tolerance scales with the geometry: both keep the same vertices.
+        let small_coords = small_simplified
+            .get_exterior_ring()
+            .unwrap()
+            .get_coord_seq()
+            .unwrap();
+        let large_coords = large_simplified
+            .get_exterior_ring()
+            .unwrap()
+            .get_coord_seq()
+            .unwrap();
+        assert_eq!(small_coords.size(), large_coords.size());
+        for i in 0..small_coords.size().unwrap() {
+            let small_x = small_coords.get_x(i).unwrap();
+            assert!((small_x * 1000. - large_coords.get_x(i).unwrap()).abs() < 1e-9);
+        }
+    }
+    assert!(
+        small
+            .simplify_relative(0.05)
+            .unwrap()
+            .get_num_coordinates()
+            .unwrap()
+            < small.get_num_coordinates().unwrap()
+    );
+
+    assert!(small.simplify_relative(0.).is_err());
+    assert!(small.simplify_relative(1.).is_err());
+    assert!(small.simplify_relative(f64::NAN).is_err());
+}
+
+#[test]
+fn test_holes_as_polygons() {
+    let polygon = Geometry::new_from_wkt(
+        "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (1 1, 3 1, 3 3, 1 3, 1 1), \
+         (5 5, 8 5, 8 6, 5 6, 5 5))",
+    )
+    .unwrap();
+    let holes = polygon.holes_as_polygons().unwrap();
+
+    assert_eq!(holes.len(), 2);
+    assert!(holes
+        .iter()
+        .all(|hole| hole.geometry_type() == Ok(GeometryTypes::Polygon)));
+    let holes_area: f64 = holes.iter().map(|hole| hole.area().unwrap()).sum();
+    let shell_area = Geometry::create_polygon(
+        polygon.get_exterior_ring().unwrap().clone().unwrap(),
+        vec![],
+    )
+    .unwrap()
+    .area()
+    .unwrap();
+    assert_almost_eq(holes_area, shell_area - polygon.area().unwrap());
+
+    let multi_polygon = Geometry::new_from_wkt("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)))").unwrap();
+    assert!(multi_polygon.holes_as_polygons().is_err());
+}
+
+#[test]
+fn test_transform_coords_bulk() {
+    let geom = Geometry::new_from_wkt(
+        "GEOMETRYCOLLECTION (POINT Z (1 2 3), LINESTRING (0 0, 1 1), \
+         MULTIPOLYGON (((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1))))",
+    )
+    .unwrap();
+    let mut calls = 0;
+    let shifted = geom
+        .transform_coords_bulk(|xs, ys| {
+            calls += 1;
+            assert_eq!(xs.len(), 12);
+            assert_eq!(ys.len(), 12);
+            xs.iter_mut().for_each(|x| *x += 1.);
+            ys.iter_mut().for_each(|y| *y -= 1.);
+        })
+        .unwrap();
+    assert_eq!(calls, 1);
+
+    let expected = Geometry::new_from_wkt(
+        "GEOMETRYCOLLECTION (POINT Z (2 1 3), LINESTRING (1 -1, 2 0), \
+         MULTIPOLYGON (((1 -1, 5 -1, 5 3, 1 3, 1 -1), (2 0, 3 0, 3 1, 2 0))))",
+    )
+    .unwrap();
+    assert!(shifted.equals_exact(&expected, 0.).unwrap());
+    let point = shifted.get_geometry_n(0).unwrap();
+    assert_eq!(point.get_coord_seq().unwrap().get_z(0), Ok(3.));
+}
+
+#[test]
+fn test_is_rectangle() {
+    let geom = Geometry::new_from_wkt("LINESTRING (-3 1, 5 7)").unwrap();
+    let envelope = geom.envelope().unwrap();
+    assert!(envelope.is_rectangle().unwrap());
+
+    let diamond = Geometry::new_from_wkt("POLYGON ((1 0, 2 1, 1 2, 0 1, 1 0))").unwrap();
+    assert!(!diamond.is_rectangle().unwrap());
+
+    let bowtie = Geometry::new_from_wkt("POLYGON ((0 0, 1 1, 1 0, 0 1, 0 0))").unwrap();
+    assert!(!bowtie.is_rectangle().unwrap());
+
+    let with_extra_vertex =
+        Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 2 0, 2 1, 0 1, 0 0))").unwrap();
+    assert!(!with_extra_vertex.is_rectangle().unwrap());
+
+    let with_hole =
+        Geometry::new_from_wkt("POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1))")
+            .unwrap();
+    assert!(!with_hole.is_rectangle().unwrap());
+
+    assert!(geom.is_rectangle().is_err());
+}
+
+#[test]
+#[cfg(feature = "v3_8_0")]
+fn test_enclosing_circle() {
+    let (x, y, radius) = Geometry::enclosing_circle(&[(0., 0.), (6., 0.), (0., 8.)]).unwrap();
+    assert_almost_eq(x, 3.);
+    assert_almost_eq(y, 4.);
+    assert_almost_eq(radius, 5.);
+
+    assert!(Geometry::enclosing_circle(&[]).is_err());
+}
+
+#[test]
+fn test_clip_lines() {
+    let lines = Geometry::new_from_wkt(
+        "MULTILINESTRING((0 10, 4 10), (-2 1, 1 1, 1 3, 6 3), (0 -1, 4 -1), (-1 0, 0 0))",
+    )
+    .unwrap();
+    let mask = Geometry::new_from_wkt("POLYGON((0 0, 4 0, 4 4, 0 4, 0 0))").unwrap();
+
+    let clipped = lines.clip_lines(&mask).unwrap();
+    assert_eq!(clipped.len(), 1);
+    assert_eq!(clipped[0].0, 1);
+    let expected = Geometry::new_from_wkt("LINESTRING(0 1, 1 1, 1 3, 4 3)").unwrap();
+    assert!(clipped[0].1.equals(&expected).unwrap());
+
+    // A U entering the mask twice, and a line crossing it then touching its corner.
+    let lines = Geometry::new_from_wkt(
+        "MULTILINESTRING((1 6, 1 -2, 3 -2, 3 6), (2 6, 2 2, 6 2, 4 4, 5 5))",
+    )
+    .unwrap();
+    let clipped = lines.clip_lines(&mask).unwrap();
+    assert_eq!(clipped.len(), 3);
+    for (index, wkt) in [
+        (0, "LINESTRING(1 4, 1 0)"),
+        (0, "LINESTRING(3 0, 3 4)"),
+        (1, "LINESTRING(2 4, 2 2, 4 2)"),
+    ] {
+        let expected = Geometry::new_from_wkt(wkt).unwrap();
+        assert!(clipped
+            .iter()
+            .any(|(n, line)| *n == index && line.equals(&expected).unwrap()));
+    }
+    for (_, line) in &clipped {
+        assert_eq!(line.geometry_type(), Ok(GeometryTypes::LineString));
+    }
+
+    let point = Geometry::new_from_wkt("POINT(1 1)").unwrap();
+    assert!(point.clip_lines(&mask).is_err());
+}
+
+#[test]
+#[cfg(feature = "v3_10_0")]
+fn test_prepare_for_reprojection() {
+    use crate::functions::for_each_coord_seq;
+
+    // A bowtie, which isn't valid.
+    let geom = Geometry::new_from_wkt("POLYGON((0 0, 10 10, 10 0, 0 10, 0 0))").unwrap();
+    let prepared = geom.prepare_for_reprojection(1.5).unwrap();
+
+    assert!(prepared.is_valid().unwrap());
+    assert_almost_eq(prepared.area().unwrap(), 50.);
+    let mut longest: f64 = 0.;
+    for_each_coord_seq(&prepared, &mut |coords| {
+        for i in 1..coords.size()? {
+            let dx = coords.get_x(i)? - coords.get_x(i - 1)?;
+            let dy = coords.get_y(i)? - coords.get_y(i - 1)?;
+            longest = longest.max(dx.hypot(dy));
+        }
+        Ok(())
+    })
+    .unwrap();
+    assert!(longest <= 1.5);
+
+    assert!(geom.prepare_for_reprojection(0.).is_err());
+}
+
+#[test]
+fn test_get_ordinate() {
+    use crate::Ordinate;
+
+    let point = Geometry::new_from_wkt("POINT (1 2)").unwrap();
+    assert_eq!(point.get_ordinate(0, Ordinate::X), Ok(1.));
+    assert_eq!(point.get_ordinate(0, Ordinate::Y), Ok(2.));
+    assert!(point.get_ordinate(0, Ordinate::Z).is_err());
+    assert!(point.get_ordinate(1, Ordinate::X).is_err());
+
+    let polygon = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 0))").unwrap();
+    assert!(polygon.get_ordinate(0, Ordinate::X).is_err());
+}
+
+#[test]
+#[cfg(feature = "v3_14_0")]
+fn test_get_ordinate_m() {
+    use crate::Ordinate;
+
+    let point = Geometry::new_from_wkt("POINT ZM (1 2 3 4)").unwrap();
+    assert_eq!(point.get_ordinate(0, Ordinate::Z), Ok(3.));
+    assert_eq!(point.get_ordinate(0, Ordinate::M), Ok(4.));
+
+    let point = Geometry::new_from_wkt("POINT Z (1 2 3)").unwrap();
+    assert!(point.get_ordinate(0, Ordinate::M).is_err());
+}
+
+#[test]
+fn test_chunk_line() {
+    let line = Geometry::new_from_wkt(
+        "LINESTRING Z (0 0 0, 1 0 1, 2 1 2, 3 1 3, 4 0 4, 5 0 5, 6 2 6, 7 2 7, 8 1 8, 9 0 9)",
+    )
+    .unwrap();
+    let chunks = line.chunk_line(4).unwrap();
+
+    assert_eq!(chunks.geometry_type(), Ok(GeometryTypes::MultiLineString));
+    assert_eq!(chunks.get_num_geometries(), Ok(3));
+    for n in 0..3 {
+        let chunk = chunks.get_geometry_n(n).unwrap();
+        assert!(chunk.get_num_points().unwrap() <= 4);
+        assert!(chunk.has_z().unwrap());
+    }
+    let merged = chunks.line_merge().unwrap();
+    assert!(merged.equals_exact(&line, 0.).unwrap());
+
+    let short = Geometry::new_from_wkt("LINESTRING (0 0, 1 1)").unwrap();
+    assert_eq!(short.chunk_line(2).unwrap().get_num_geometries(), Ok(1));
+    assert!(short.chunk_line(1).is_err());
+    assert!(chunks.chunk_line(4).is_err());
+}
+
+#[test]
+fn test_convex_hull_vertices() {
+    let geom = Geometry::new_from_wkt(
+        "MULTIPOINT((0 0), (3 1), (10 0), (5 5), (12 6), (7 3), (10 10), (2 8), (0 10), (1 4))",
+    )
+    .unwrap();
+    let (hull, corners) = geom.convex_hull_vertices().unwrap();
+
+    assert_eq!(hull.geometry_type(), Ok(GeometryTypes::Polygon));
+    let ring = hull.get_exterior_ring().unwrap();
+    assert_eq!(
+        corners.get_num_geometries().unwrap(),
+        ring.get_num_points().unwrap() - 1
+    );
+    assert_eq!(corners.get_num_geometries(), Ok(5));
+    for n in 0..corners.get_num_geometries().unwrap() {
+        let corner = corners.get_geometry_n(n).unwrap();
+        assert!(geom.contains(&corner).unwrap());
+        assert!(hull.boundary().unwrap().intersects(&corner).unwrap());
+    }
+
+    let line = Geometry::new_from_wkt("MULTIPOINT((0 0), (1 1), (2 2))").unwrap();
+    let (hull, corners) = line.convex_hull_vertices().unwrap();
+    assert_eq!(hull.geometry_type(), Ok(GeometryTypes::LineString));
+    assert_eq!(corners.get_num_geometries(), Ok(2));
+}
+
+#[test]
+fn test_split_antimeridian() {
+    let line = Geometry::new_from_wkt("LINESTRING(170 -10, 175 0, -170 30)").unwrap();
+    let split = line.split_antimeridian().unwrap();
+
+    assert_eq!(split.geometry_type(), Ok(GeometryTypes::MultiLineString));
+    assert_eq!(split.get_num_geometries(), Ok(2));
+    let east = split.get_geometry_n(0).unwrap();
+    let west = split.get_geometry_n(1).unwrap();
+    let east_end = east.get_end_point().unwrap().get_coord_seq().unwrap();
+    let west_start = west.get_start_point().unwrap().get_coord_seq().unwrap();
+    assert_eq!(east_end.get_x(0), Ok(180.));
+    assert_eq!(west_start.get_x(0), Ok(-180.));
+    assert_almost_eq(east_end.get_y(0).unwrap(), 10.);
+    assert_almost_eq(west_start.get_y(0).unwrap(), 10.);
+
+    // Going west, and a line which doesn't cross at all.
+    let lines =
+        Geometry::new_from_wkt("MULTILINESTRING((-175 0, 175 0, 170 5), (0 0, 10 10))").unwrap();
+    let split = lines.split_antimeridian().unwrap();
+    let expected = Geometry::new_from_wkt(
+        "MULTILINESTRING((-175 0, -180 0), (180 0, 175 0, 170 5), (0 0, 10 10))",
+    )
+    .unwrap();
+    assert!(split.equals_exact(&expected, 0.).unwrap());
+
+    // Already split on the antimeridian: nothing is interpolated or added there.
+    let line = Geometry::new_from_wkt("LINESTRING(179 0, 180 0, -180 5, -179 5)").unwrap();
+    let split = line.split_antimeridian().unwrap();
+    let expected =
+        Geometry::new_from_wkt("MULTILINESTRING((179 0, 180 0), (-180 5, -179 5))").unwrap();
+    assert!(split.equals_exact(&expected, 0.).unwrap());
+    let line = Geometry::new_from_wkt("LINESTRING(180 0, -170 10)").unwrap();
+    let expected = Geometry::new_from_wkt("MULTILINESTRING((-180 0, -170 10))").unwrap();
+    assert!(line
+        .split_antimeridian()
+        .unwrap()
+        .equals_exact(&expected, 0.)
+        .unwrap());
+
+    let point = Geometry::new_from_wkt("POINT(0 0)").unwrap();
+    assert!(point.split_antimeridian().is_err());
+}
+
+#[test]
+fn test_ring_self_intersection_points() {
+    let geom = Geometry::new_from_wkt(
+        "POLYGON((0 0, 4 4, 4 0, 0 4, 0 0), (1 0.5, 1.5 0.5, 1.5 1, 1 1, 1 0.5))",
+    )
+    .unwrap();
+    let points = geom.ring_self_intersection_points().unwrap();
+    let expected = Geometry::new_from_wkt("MULTIPOINT((2 2))").unwrap();
+    assert!(points.equals_exact(&expected, 0.).unwrap());
+
+    // The hole crosses the exterior ring, but both rings are simple.
+    let geom =
+        Geometry::new_from_wkt("POLYGON((0 0, 4 0, 4 4, 0 4, 0 0), (3 1, 5 1, 5 2, 3 2, 3 1))")
+            .unwrap();
+    assert!(geom
+        .ring_self_intersection_points()
+        .unwrap()
+        .is_empty()
+        .unwrap());
+
+    let line = Geometry::new_from_wkt("LINESTRING(0 0, 2 2, 2 0, 0 2)").unwrap();
+    assert!(line.ring_self_intersection_points().is_err());
+}
+
+#[test]
+fn test_variable_buffer() {
+    let line = Geometry::new_from_wkt("LINESTRING(0 0, 10 0, 20 0)").unwrap();
+    let spindle = line.variable_buffer(&[1., 2., 1.]).unwrap();
+
+    assert_eq!(spindle.geometry_type(), Ok(GeometryTypes::Polygon));
+    assert!(spindle.is_valid().unwrap());
+    let middle = Geometry::new_from_wkt("POINT(10 1.9)").unwrap();
+    let start = Geometry::new_from_wkt("POINT(0 1.5)").unwrap();
+    let end = Geometry::new_from_wkt("POINT(20 1.5)").unwrap();
+    assert!(spindle.contains(&middle).unwrap());
+    assert!(!spindle.contains(&start).unwrap());
+    assert!(!spindle.contains(&end).unwrap());
+    // Wider than the buffer at the smallest distance, thinner than at the largest.
+    assert!(spindle.area().unwrap() > line.buffer(1., 8).unwrap().area().unwrap());
+    assert!(spindle.area().unwrap() < line.buffer(2., 8).unwrap().area().unwrap());
+
+    // The first segment has no width, only the second one is buffered.
+    let cone = line.variable_buffer(&[0., 0., 1.]).unwrap();
+    assert_eq!(cone.geometry_type(), Ok(GeometryTypes::Polygon));
+    let on_first = Geometry::new_from_wkt("POINT(5 0)").unwrap();
+    let on_second = Geometry::new_from_wkt("POINT(15 0)").unwrap();
+    assert!(!cone.intersects(&on_first).unwrap());
+    assert!(cone.contains(&on_second).unwrap());
+    let flat = line.variable_buffer(&[0., 0., 0.]).unwrap();
+    assert_eq!(flat.geometry_type(), Ok(GeometryTypes::Polygon));
+    assert!(flat.is_empty().unwrap());
+
+    assert!(line.variable_buffer(&[1., 2.]).is_err());
+    assert!(line.variable_buffer(&[1., -2., 1.]).is_err());
+}
+
+#[test]
+#[cfg(feature = "v3_10_0")]
+fn test_triangulate() {
+    // Clockwise on purpose.
+    let pentagon = Geometry::new_from_wkt("POLYGON((0 0, 0 2, 2 3, 4 2, 4 0, 0 0))").unwrap();
+    let triangles = pentagon.triangulate().unwrap();
+
+    assert_eq!(
+        triangles.geometry_type(),
+        Ok(GeometryTypes::GeometryCollection)
+    );
+    assert_eq!(triangles.get_num_geometries(), Ok(3));
+    let mut area = 0.;
+    for n in 0..3 {
+        let triangle = triangles.get_geometry_n(n).unwrap();
+        assert_eq!(triangle.get_num_coordinates(), Ok(4));
+        assert!(triangle
+            .get_exterior_ring()
+            .unwrap()
+            .get_coord_seq()
+            .unwrap()
+            .is_ccw()
+            .unwrap());
+        area += triangle.area().unwrap();
+    }
+    assert_almost_eq(area, pentagon.area().unwrap());
+    assert!(triangles.unary_union().unwrap().equals(&pentagon).unwrap());
+
+    let concave = Geometry::new_from_wkt("POLYGON((0 0, 4 0, 4 4, 2 1, 0 4, 0 0))").unwrap();
+    let triangles = concave.triangulate().unwrap();
+    assert_eq!(triangles.get_num_geometries(), Ok(3));
+    assert_almost_eq(triangles.area().unwrap(), concave.area().unwrap());
+
+    let line = Geometry::new_from_wkt("LINESTRING(0 0, 1 1)").unwrap();
+    assert!(line.triangulate().is_err());
+}
+
+#[test]
+fn test_nearest_segment() {
+    let line = Geometry::new_from_wkt("LINESTRING (0 0, 10 0, 10 10)").unwrap();
+    let point = Geometry::new_from_wkt("POINT (5 1)").unwrap();
+    let expected = Geometry::new_from_wkt("LINESTRING (0 0, 10 0)").unwrap();
+    assert!(line
+        .nearest_segment(&point)
+        .unwrap()
+        .equals_exact(&expected, 0.)
+        .unwrap());
+
+    // Past the end of the line.
+    let point = Geometry::new_from_wkt("POINT (11 20)").unwrap();
+    let expected = Geometry::new_from_wkt("LINESTRING (10 0, 10 10)").unwrap();
+    assert!(line
+        .nearest_segment(&point)
+        .unwrap()
+        .equals_exact(&expected, 0.)
+        .unwrap());
+
+    let lines = Geometry::new_from_wkt("MULTILINESTRING ((0 0, 1 0), (5 5, 5 8, 6 8))").unwrap();
+    let point = Geometry::new_from_wkt("POINT (6 6)").unwrap();
+    let expected = Geometry::new_from_wkt("LINESTRING (5 5, 5 8)").unwrap();
+    assert!(lines
+        .nearest_segment(&point)
+        .unwrap()
+        .equals_exact(&expected, 0.)
+        .unwrap());
+
+    let empty = Geometry::new_from_wkt("LINESTRING EMPTY").unwrap();
+    assert!(empty.nearest_segment(&point).is_err());
+    let polygon = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 0))").unwrap();
+    assert!(polygon.nearest_segment(&point).is_err());
+}
+
+#[test]
+fn test_restore_z_from() {
+    let reference = Geometry::new_from_wkt("LINESTRING Z (0 0 1, 5 0 2, 5 5 3)").unwrap();
+    let line = Geometry::new_from_wkt("LINESTRING (0 0, 5 0, 5 5)").unwrap();
+    let restored = line.restore_z_from(&reference).unwrap();
+
+    assert!(restored.has_z().unwrap());
+    let coords = restored.get_coord_seq().unwrap();
+    assert_eq!(coords.get_z(0), Ok(1.));
+    assert_eq!(coords.get_z(1), Ok(2.));
+    assert_eq!(coords.get_z(2), Ok(3.));
+    assert!(restored.equals_exact(&line, 0.).unwrap());
+
+    let polygon = Geometry::new_from_wkt("POLYGON ((0 0, 5 0, 5 5, 0 0))").unwrap();
+    let restored = polygon.restore_z_from(&reference).unwrap();
+    assert_eq!(restored.geometry_type(), Ok(GeometryTypes::Polygon));
+    let ring = restored
+        .get_exterior_ring()
+        .unwrap()
+        .get_coord_seq()
+        .unwrap();
+    assert_eq!(ring.get_z(3), Ok(1.));
+
+    assert!(line.restore_z_from(&line).is_err());
+}
+
+#[test]
+fn test_area_within() {
+    use crate::PreparedMask;
+
+    let mask = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))").unwrap();
+    let prepared_mask = PreparedMask::new(&mask).unwrap();
+    let square = Geometry::new_from_wkt("POLYGON((8 2, 12 2, 12 6, 8 6, 8 2))").unwrap();
+
+    assert_almost_eq(square.area_within(&mask).unwrap(), 8.);
+    assert_almost_eq(square.area_within_prepared(&prepared_mask).unwrap(), 8.);
+    let inside = Geometry::new_from_wkt("POLYGON((1 1, 3 1, 3 3, 1 3, 1 1))").unwrap();
+    assert_eq!(inside.area_within_prepared(&prepared_mask), Ok(4.));
+
+    let outside = Geometry::new_from_wkt("POLYGON((20 0, 21 0, 21 1, 20 0))").unwrap();
+    assert_eq!(outside.area_within(&mask), Ok(0.));
+    assert_eq!(outside.area_within_prepared(&prepared_mask), Ok(0.));
+
+    let line = Geometry::new_from_wkt("LINESTRING(-5 5, 5 5)").unwrap();
+    assert_eq!(line.area_within(&mask), Ok(0.));
+}
+
+#[test]
+fn test_simplify_min_feature() {
+    // Two squares linked by a bent connector, about 0.2 wide in its middle. The bumps on the
+    // squares are less than 0.5 high.
+    let dumbbell = Geometry::new_from_wkt(
+        "POLYGON((0 0, 2 -0.3, 4 0, 4 1.8, 10 0.8, 16 1.8, 16 0, 20 0, 20 4, 18 4.3, 16 4, \
+         16 2.2, 10 1, 4 2.2, 4 4, 0 4, 0 0))",
+    )
+    .unwrap();
+    let neck = Geometry::new_from_wkt("POINT(10 0.9)").unwrap();
+    let notch = Geometry::new_from_wkt("POINT(10 3)").unwrap();
+
+    // A tolerance of 1 straightens the bottom of the connector, which then crosses its top.
+    assert!(!dumbbell.simplify(1.).unwrap().covers(&neck).unwrap());
+
+    // The halved tolerance of 0.5 only removes the bumps.
+    let simplified = dumbbell.simplify_min_feature(1., 0.1).unwrap();
+    assert_eq!(simplified.geometry_type(), Ok(GeometryTypes::Polygon));
+    assert_eq!(simplified.get_num_coordinates(), Ok(15));
+    assert!(simplified.covers(&neck).unwrap());
+    assert!(!simplified.covers(&notch).unwrap());
+    // The connector is still at least 0.1 wide: it's not cut by a buffer of -0.05.
+    let wide = simplified.buffer(-0.05, 8).unwrap();
+    assert_eq!(wide.geometry_type(), Ok(GeometryTypes::Polygon));
+    assert!(wide.covers(&neck).unwrap());
+
+    // Nothing in the dumbbell is 10 wide, so nothing stops the simplification.
+    let free = dumbbell.simplify_min_feature(1., 10.).unwrap();
+    assert!(free
+        .equals_exact(&dumbbell.simplify(1.).unwrap(), 0.)
+        .unwrap());
+}
+
+#[test]
+fn test_to_svg_path() {
+    let square = Geometry::new_from_wkt("POLYGON((0 0, 1 0, 1 1, 0 1, 0 0))").unwrap();
+    assert_eq!(square.to_svg_path(0).unwrap(), "M 0 0 L 1 0 L 1 1 L 0 1 Z");
+
+    let with_hole = Geometry::new_from_wkt(
+        "MULTIPOLYGON(((0 0, 4 0, 4 4, 0 0), (1 0.5, 2 0.5, 2 1.5, 1 0.5)), ((5 5, 6 5, 6 6, 5 5)))",
+    )
+    .unwrap();
+    assert_eq!(
+        with_hole.to_svg_path(1).unwrap(),
+        "M 0.0 0.0 L 4.0 0.0 L 4.0 4.0 Z M 1.0 0.5 L 2.0 0.5 L 2.0 1.5 Z \
+         M 5.0 5.0 L 6.0 5.0 L 6.0 6.0 Z"
+    );
+
+    let point = Geometry::new_from_wkt("POINT(1.234 5.678)").unwrap();
+    assert_eq!(point.to_svg_path(2).unwrap(), "M 1.23 5.68");
+    let empty = Geometry::new_from_wkt("POLYGON EMPTY").unwrap();
+    assert_eq!(empty.to_svg_path(2).unwrap(), "");
+}
+
+#[test]
+fn test_densify_aligned() {
+    use crate::functions::collect_xy;
+
+    // Both triangles share the (0 0, 1 0.7) edge, in opposite directions.
+    let a = Geometry::new_from_wkt("POLYGON((0 0, 1 0.7, 0 1, 0 0))").unwrap();
+    let b = Geometry::new_from_wkt("POLYGON((0 0, 1 0, 1 0.7, 0 0))").unwrap();
+    let on_shared_edge = |geom: &Geometry| {
+        let mut vertices: Vec<_> = collect_xy(&geom.densify_aligned((0.05, 0.05), 0.13).unwrap())
+            .unwrap()
+            .into_iter()
+            .filter(|&(x, y)| x > 0. && x < 1. && (y - 0.7 * x).abs() < 1e-9)
+            .collect();
+        vertices.sort_by(|a, b| a.0.total_cmp(&b.0));
+        vertices
+    };
+
+    let shared = on_shared_edge(&a);
+    // 8 vertical and 5 horizontal grid lines cross the edge.
+    assert_eq!(shared.len(), 13);
+    assert_eq!(shared, on_shared_edge(&b));
+
+    let densified = a.densify_aligned((0.05, 0.05), 0.13).unwrap();
+    assert!(densified.is_valid().unwrap());
+    assert_almost_eq(densified.area().unwrap(), a.area().unwrap());
+
+    assert!(a.densify_aligned((0., 0.), 0.).is_err());
+}
+
+#[test]
+fn test_polygonize_with_holes() {
+    let linework = Geometry::new_from_wkt(
+        "MULTILINESTRING((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 8 2, 8 8, 2 8, 2 2))",
+    )
+    .unwrap();
+    let area = linework.polygonize_with_holes().unwrap();
+    let expected =
+        Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 8 2, 8 8, 2 8, 2 2))")
+            .unwrap();
+    assert_eq!(area.geometry_type(), Ok(GeometryTypes::Polygon));
+    assert!(area.equals(&expected).unwrap());
+
+    // An island within the hole.
+    let linework = Geometry::new_from_wkt(
+        "MULTILINESTRING((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 8 2, 8 8, 2 8, 2 2), \
+                         (4 4, 6 4, 6 6, 4 6, 4 4))",
+    )
+    .unwrap();
+    let area = linework.polygonize_with_holes().unwrap();
+    assert_eq!(area.geometry_type(), Ok(GeometryTypes::MultiPolygon));
+    assert_eq!(area.get_num_geometries(), Ok(2));
+    assert_almost_eq(area.area().unwrap(), 64. + 4.);
+    assert!(area.is_valid().unwrap());
+
+    // Adjacent faces are kept apart.
+    let linework =
+        Geometry::new_from_wkt("MULTILINESTRING((0 0, 1 0, 1 1, 0 1, 0 0), (1 0, 2 0, 2 1, 1 1))")
+            .unwrap();
+    let faces = linework.polygonize_with_holes().unwrap();
+    assert_eq!(faces.geometry_type(), Ok(GeometryTypes::MultiPolygon));
+    assert_eq!(faces.get_num_geometries(), Ok(2));
+    for wkt in [
+        "POLYGON((0 0, 1 0, 1 1, 0 1, 0 0))",
+        "POLYGON((1 0, 2 0, 2 1, 1 1, 1 0))",
+    ] {
+        let expected = Geometry::new_from_wkt(wkt).unwrap();
+        let found = (0..2).any(|n| faces.get_geometry_n(n).unwrap().equals(&expected).unwrap());
+        assert!(found, "{wkt} missing");
+    }
+}
+
+#[test]
+fn test_distance_matrix() {
+    let geoms = ["POINT(0 0)", "POINT(2 0)", "POINT(5 0)"]
+        .iter()
+        .map(|wkt| Geometry::new_from_wkt(wkt).unwrap())
+        .collect::<Vec<_>>();
+    let matrix = Geometry::distance_matrix(&geoms).unwrap();
+    assert_eq!(
+        matrix,
+        vec![vec![0., 2., 5.], vec![2., 0., 3.], vec![5., 3., 0.]]
+    );
+
+    // Large enough to go through the indexed distance.
+    let circle = Geometry::new_from_wkt("POINT(0 10)")
+        .unwrap()
+        .buffer(1., 32)
+        .unwrap();
+    let matrix = Geometry::distance_matrix([&geoms[0], &circle]).unwrap();
+    assert_almost_eq(matrix[0][1], 9.);
+    assert_eq!(matrix[0][1], matrix[1][0]);
+
+    let empty: Vec<Geometry> = Vec::new();
+    assert!(Geometry::distance_matrix(&empty).unwrap().is_empty());
+}
+
+#[test]
+fn test_buffer_ring() {
+    let point = Geometry::new_from_wkt("POINT(5 5)").unwrap();
+    let annulus = point.buffer_ring(1., 2., 32).unwrap();
+
+    assert_eq!(annulus.geometry_type(), Ok(GeometryTypes::Polygon));
+    assert_eq!(annulus.get_num_interior_rings(), Ok(1));
+    let expected = std::f64::consts::PI * (4. - 1.);
+    assert!((annulus.area().unwrap() - expected).abs() < expected * 1e-2);
+    assert!(!annulus.contains(&point).unwrap());
+
+    // Starting at the point itself.
+    let disc = point.buffer_ring(0., 2., 32).unwrap();
+    assert_eq!(disc.get_num_interior_rings(), Ok(0));
+
+    assert!(point.buffer_ring(2., 1., 32).is_err());
+    assert!(point.buffer_ring(1., 1., 32).is_err());
+    assert!(point.buffer_ring(-1., 1., 32).is_err());
+}
+
+#[test]
+fn test_vertex_angles() {
+    use std::f64::consts::FRAC_PI_2;
+
+    let line = Geometry::new_from_wkt("LINESTRING (0 0, 1 0, 1 1)").unwrap();
+    let angles = line.vertex_angles().unwrap();
+    assert_eq!(angles.len(), 1);
+    assert_almost_eq(angles[0], FRAC_PI_2);
+
+    // Clockwise square: every corner turns right.
+    let ring = Geometry::new_from_wkt("LINEARRING (0 0, 0 1, 1 1, 1 0, 0 0)").unwrap();
+    let angles = ring.vertex_angles().unwrap();
+    assert_eq!(angles.len(), 4);
+    for angle in angles {
+        assert_almost_eq(angle, -FRAC_PI_2);
+    }
+
+    let two_points = Geometry::new_from_wkt("LINESTRING (0 0, 1 0)").unwrap();
+    assert!(two_points.vertex_angles().unwrap().is_empty());
+    let polygon = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 0))").unwrap();
+    assert!(polygon.vertex_angles().is_err());
+}
+
+#[test]
+#[cfg(feature = "v3_12_0")]
+fn test_coverage_union_checked() {
+    let overlapping = Geometry::new_from_wkt(
+        "GEOMETRYCOLLECTION(POLYGON((0 0, 2 0, 2 2, 0 2, 0 0)), POLYGON((1 0, 3 0, 3 2, 1 2, 1 0)))",
+    )
+    .unwrap();
+    match overlapping.coverage_union_checked(0.) {
+        Err(crate::Error::GenericError(message)) => {
+            assert!(message.contains("member 0"), "{message}");
+            assert!(message.contains("member 1"), "{message}");
+        }
+        Err(e) => panic!("expected an invalid coverage error, got {e:?}"),
+        Ok(_) => panic!("expected an invalid coverage error"),
+    }
+
+    let coverage = Geometry::new_from_wkt(
+        "GEOMETRYCOLLECTION(POLYGON((0 0, 1 0, 1 1, 0 1, 0 0)), POLYGON((1 0, 2 0, 2 1, 1 1, 1 0)))",
+    )
+    .unwrap();
+    let union = coverage.coverage_union_checked(0.).unwrap();
+    let expected = Geometry::new_from_wkt("POLYGON((0 0, 2 0, 2 1, 0 1, 0 0))").unwrap();
+    assert_eq!(union.geometry_type(), Ok(GeometryTypes::Polygon));
+    assert!(union.equals(&expected).unwrap());
+}